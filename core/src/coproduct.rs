@@ -72,9 +72,19 @@
 use crate::hlist::{HCons, HNil};
 use crate::indices::{Here, There};
 use crate::traits::{Func, Poly, ToMut, ToRef};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::any::Any;
+use core::future::Future;
+use core::iter::FusedIterator;
+use core::pin::Pin;
+use core::task::{Context, Poll};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "serde")]
+pub mod serde_repr;
+
 /// Enum type representing a Coproduct. Think of this as a Result, but capable
 /// of supporting any arbitrary number of types instead of just 2.
 ///
@@ -232,6 +242,147 @@ impl<Head, Tail> Coproduct<Head, Tail> {
         CoproductTaker::take(self)
     }
 
+    /// Instantiate a coproduct at a type-level position.
+    ///
+    /// Unlike [`inject`](Coproduct::inject), which finds the variant by type, this
+    /// selects it by position, so it also works when a type appears more than once.
+    /// Positions are written with the index types from [`crate::indices`]: `Here`
+    /// is the first variant, `There<Here>` the second, and so on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::indices::{Here, There};
+    /// use frunk_core::Coprod;
+    ///
+    /// // A request or a response, both carrying an i32 payload
+    /// type Message = Coprod!(i32, i32, String);
+    ///
+    /// let request = Message::inject_at::<Here>(7);
+    /// let response = Message::inject_at::<There<Here>>(7);
+    ///
+    /// assert_ne!(request, response);
+    /// assert_eq!(response.variant_index(), 1);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn inject_at<Index>(to_insert: <Self as CoprodAt<Index>>::Value) -> Self
+    where
+        Self: CoprodAt<Index>,
+    {
+        CoprodAt::inject_at(to_insert)
+    }
+
+    /// Borrow the element at a type-level position, if that is the variant present.
+    ///
+    /// Please see [`inject_at`](Coproduct::inject_at) for how positions are written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::indices::{Here, There};
+    /// use frunk_core::Coprod;
+    ///
+    /// type Message = Coprod!(i32, i32, String);
+    ///
+    /// let response = Message::inject_at::<There<Here>>(7);
+    /// assert_eq!(response.get_at::<Here>(), None);
+    /// assert_eq!(response.get_at::<There<Here>>(), Some(&7));
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn get_at<Index>(&self) -> Option<&<Self as CoprodAt<Index>>::Value>
+    where
+        Self: CoprodAt<Index>,
+    {
+        CoprodAt::get_at(self)
+    }
+
+    /// Retrieve the element at a type-level position, if that is the variant present.
+    ///
+    /// Please see [`inject_at`](Coproduct::inject_at) for how positions are written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::indices::{Here, There};
+    /// use frunk_core::Coprod;
+    ///
+    /// type Message = Coprod!(i32, i32, String);
+    ///
+    /// let note = Message::inject_at::<There<There<Here>>>(String::from("hi"));
+    /// assert_eq!(note.clone().take_at::<There<Here>>(), None);
+    /// assert_eq!(note.take_at::<There<There<Here>>>(), Some(String::from("hi")));
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn take_at<Index>(self) -> Option<<Self as CoprodAt<Index>>::Value>
+    where
+        Self: CoprodAt<Index>,
+    {
+        CoprodAt::take_at(self)
+    }
+
+    /// Downcast a boxed dynamic value into a coproduct, trying each variant type
+    /// in order.
+    ///
+    /// If the value is none of the variant types, the box is given back unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::Coprod;
+    /// use std::any::Any;
+    ///
+    /// type I32Str = Coprod!(i32, String);
+    ///
+    /// let boxed: Box<dyn Any> = Box::new(String::from("plugin"));
+    /// let co = I32Str::try_from_any(boxed).unwrap();
+    /// assert_eq!(co, I32Str::inject(String::from("plugin")));
+    ///
+    /// let boxed: Box<dyn Any> = Box::new(1.5f32);
+    /// let rejected = I32Str::try_from_any(boxed).unwrap_err();
+    /// assert_eq!(rejected.downcast_ref::<f32>(), Some(&1.5));
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    pub fn try_from_any(boxed: Box<dyn Any>) -> Result<Self, Box<dyn Any>>
+    where
+        Self: CoproductAny,
+    {
+        CoproductAny::try_from_any(boxed)
+    }
+
+    /// Box the value held by the coproduct as a dynamic value.
+    ///
+    /// This is the inverse of [`try_from_any`](Coproduct::try_from_any).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::Coprod;
+    ///
+    /// type I32Str = Coprod!(i32, String);
+    ///
+    /// let boxed = I32Str::inject(3).into_any();
+    /// assert_eq!(boxed.downcast_ref::<i32>(), Some(&3));
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    pub fn into_any(self) -> Box<dyn Any>
+    where
+        Self: CoproductAny,
+    {
+        CoproductAny::into_any(self)
+    }
+
     /// Attempt to extract a value from a coproduct (or get the remaining possibilities).
     ///
     /// By chaining calls to this, one can exhaustively match all variants of a coproduct.
@@ -580,6 +731,73 @@ impl<Head, Tail> Coproduct<Head, Tail> {
         CoproductFoldable::fold(self, folder)
     }
 
+    /// Use functions to fold a borrowed coproduct into a single value, without
+    /// consuming it.
+    ///
+    /// This is shorthand for `self.to_ref().fold(folder)`, so the functions receive
+    /// references to the variants. See [`fold`](Coproduct::fold) for the kinds of
+    /// folder that are supported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::{hlist, poly_fn, Coprod};
+    ///
+    /// type I32Str = Coprod!(i32, String);
+    ///
+    /// let co = I32Str::inject(String::from("event"));
+    ///
+    /// let len = co.fold_ref(hlist![|_: &i32| 0, |s: &String| s.len()]);
+    /// assert_eq!(len, 5);
+    ///
+    /// let kind = co.fold_ref(poly_fn![
+    ///     |_s: &String| -> &'static str { "string" },
+    ///     |_n: &i32| -> &'static str { "int" },
+    /// ]);
+    /// assert_eq!(kind, "string");
+    ///
+    /// // co was only borrowed
+    /// assert_eq!(co.take(), Some(String::from("event")));
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn fold_ref<'a, Output, Folder>(&'a self, folder: Folder) -> Output
+    where
+        Self: ToRef<'a>,
+        <Self as ToRef<'a>>::Output: CoproductFoldable<Folder, Output>,
+    {
+        CoproductFoldable::fold(self.to_ref(), folder)
+    }
+
+    /// Use functions to fold a mutably borrowed coproduct into a single value,
+    /// without consuming it.
+    ///
+    /// This is shorthand for `self.to_mut().fold(folder)`, so the functions receive
+    /// mutable references to the variants.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::{hlist, Coprod};
+    ///
+    /// type I32Str = Coprod!(i32, String);
+    ///
+    /// let mut co = I32Str::inject(41);
+    /// co.fold_mut(hlist![|n: &mut i32| *n += 1, |s: &mut String| s.push('!')]);
+    /// assert_eq!(co, I32Str::inject(42));
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn fold_mut<'a, Output, Folder>(&'a mut self, folder: Folder) -> Output
+    where
+        Self: ToMut<'a>,
+        <Self as ToMut<'a>>::Output: CoproductFoldable<Folder, Output>,
+    {
+        CoproductFoldable::fold(self.to_mut(), folder)
+    }
+
     /// Apply a function to each variant of a Coproduct.
     ///
     /// The transforms some `Coprod!(A, B, C, ..., E)` into some
@@ -658,10 +876,83 @@ impl<Head, Tail> Coproduct<Head, Tail> {
     {
         CoproductMappable::map(self, mapper)
     }
-}
 
-impl<T> Coproduct<T, CNil> {
-    /// Extract the value from a coproduct with only one variant.
+    /// Use functions to map each variant of a coproduct to a coproduct, and
+    /// flatten the results into a single coproduct.
+    ///
+    /// The result holds the variants of every branch's output, in order. Variants
+    /// that appear in more than one branch are not merged; use
+    /// [`embed`](Coproduct::embed) afterwards if you need a smaller coproduct.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use frunk::{hlist, Coprod};
+    ///
+    /// type I32Str = Coprod!(i32, &'static str);
+    /// type U8BoolChar = Coprod!(u8, bool, char);
+    ///
+    /// let mapper = hlist![
+    ///     |n: i32| if n < 0 { <Coprod!(u8, bool)>::inject(false) } else { <Coprod!(u8, bool)>::inject(n as u8) },
+    ///     |s: &str| <Coprod!(char)>::inject(s.chars().next().unwrap_or(' ')),
+    /// ];
+    ///
+    /// assert_eq!(I32Str::inject(-1).flat_map(&mapper), U8BoolChar::inject(false));
+    /// assert_eq!(I32Str::inject(3).flat_map(&mapper), U8BoolChar::inject(3u8));
+    /// assert_eq!(I32Str::inject("hi").flat_map(&mapper), U8BoolChar::inject('h'));
+    /// ```
+    #[inline(always)]
+    pub fn flat_map<F>(
+        self,
+        mapper: F,
+    ) -> <<Self as CoproductMappable<F>>::Output as CoproductFlatten>::Output
+    where
+        Self: CoproductMappable<F>,
+        <Self as CoproductMappable<F>>::Output: CoproductFlatten,
+    {
+        CoproductMappable::map(self, mapper).flatten()
+    }
+
+    /// Use fallible functions to map each variant of a coproduct, short-circuiting
+    /// with the error if the function for the present variant fails.
+    ///
+    /// Every function must return a `Result` with the same error type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use frunk::{hlist, Coprod};
+    ///
+    /// type StrBool = Coprod!(&'static str, bool);
+    /// type I32Bool = Coprod!(i32, bool);
+    ///
+    /// let mapper = hlist![
+    ///     |s: &str| s.parse::<i32>().map_err(|e| e.to_string()),
+    ///     |b: bool| if b { Ok(b) } else { Err(String::from("false")) },
+    /// ];
+    ///
+    /// assert_eq!(StrBool::inject("12").try_map(&mapper), Ok(I32Bool::inject(12)));
+    /// assert_eq!(StrBool::inject(true).try_map(&mapper), Ok(I32Bool::inject(true)));
+    /// assert_eq!(StrBool::inject(false).try_map(&mapper), Err(String::from("false")));
+    /// assert!(StrBool::inject("x").try_map(&mapper).is_err());
+    /// ```
+    #[inline(always)]
+    pub fn try_map<F, E>(
+        self,
+        mapper: F,
+    ) -> Result<<<Self as CoproductMappable<F>>::Output as CoproductTranspose<E>>::Ok, E>
+    where
+        Self: CoproductMappable<F>,
+        <Self as CoproductMappable<F>>::Output: CoproductTranspose<E>,
+    {
+        CoproductMappable::map(self, mapper).transpose()
+    }
+
+    /// Widen a coproduct by appending the variants of another coproduct type
+    /// after its own.
+    ///
+    /// This is the value-level counterpart of the type-level append
+    /// performed by [`CoprodAppend`]: the value is left in its current variant.
     ///
     /// # Example
     ///
@@ -669,576 +960,1898 @@ impl<T> Coproduct<T, CNil> {
     /// # fn main() {
     /// use frunk_core::Coprod;
     ///
-    /// type I32Only = Coprod!(i32);
-    /// let co = I32Only::inject(5);
+    /// type I32Bool = Coprod!(i32, bool);
+    /// type StrF32 = Coprod!(&'static str, f32);
+    /// type I32BoolStrF32 = Coprod!(i32, bool, &'static str, f32);
     ///
-    /// assert_eq!(co.extract(), 5);
+    /// let co = I32Bool::inject(true);
+    /// let extended: I32BoolStrF32 = co.extend_right::<StrF32>();
+    /// assert_eq!(extended, I32BoolStrF32::inject(true));
     /// # }
     /// ```
     #[inline(always)]
-    pub fn extract(self) -> T {
-        match self {
-            Coproduct::Inl(v) => v,
-            Coproduct::Inr(never) => match never {},
-        }
+    pub fn extend_right<Right>(self) -> <Self as CoprodAppend<Right>>::Output
+    where
+        Self: CoprodAppend<Right>,
+    {
+        CoprodAppend::extend_right(self)
     }
-}
 
-/// Trait for instantiating a coproduct from an element
-///
-/// This trait is part of the implementation of the inherent static method
-/// [`Coproduct::inject`]. Please see that method for more information.
-///
-/// You only need to import this trait when working with generic
-/// Coproducts of unknown type. In most code, `Coproduct::inject` will
-/// "just work," with or without this trait.
-///
-/// [`Coproduct::inject`]: enum.Coproduct.html#method.inject
-pub trait CoprodInjector<InjectType, Index> {
-    /// Instantiate a coproduct from an element.
+    /// Widen a coproduct by prepending the variants of another coproduct type
+    /// before its own.
     ///
-    /// Please see the [inherent static method] for more information.
+    /// # Example
     ///
-    /// The only difference between that inherent method and this
-    /// trait method is the location of the type parameters.
-    /// (here, they are on the trait rather than the method)
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::Coprod;
     ///
-    /// [inherent static method]: enum.Coproduct.html#method.inject
-    fn inject(to_insert: InjectType) -> Self;
-}
-
-impl<I, Tail> CoprodInjector<I, Here> for Coproduct<I, Tail> {
-    fn inject(to_insert: I) -> Self {
-        Coproduct::Inl(to_insert)
+    /// type I32Bool = Coprod!(i32, bool);
+    /// type StrF32 = Coprod!(&'static str, f32);
+    /// type I32BoolStrF32 = Coprod!(i32, bool, &'static str, f32);
+    ///
+    /// let co = StrF32::inject(42f32);
+    /// let extended: I32BoolStrF32 = co.extend_left::<I32Bool>();
+    /// assert_eq!(extended, I32BoolStrF32::inject(42f32));
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn extend_left<Left>(self) -> <Left as CoprodAppend<Self>>::Output
+    where
+        Left: CoprodAppend<Self>,
+    {
+        Left::extend_left(self)
     }
-}
 
-impl<Head, I, Tail, TailIndex> CoprodInjector<I, There<TailIndex>> for Coproduct<Head, Tail>
-where
-    Tail: CoprodInjector<I, TailIndex>,
-{
-    fn inject(to_insert: I) -> Self {
-        let tail_inserted = <Tail as CoprodInjector<I, TailIndex>>::inject(to_insert);
-        Coproduct::Inr(tail_inserted)
+    /// Flatten a coproduct of coproducts into a single coproduct.
+    ///
+    /// Every variant must itself be a coproduct; the variants of the result are
+    /// those of each nested coproduct, concatenated in order. A plain variant can
+    /// take part by first being mapped into a single-variant coproduct.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::{hlist, Coprod};
+    ///
+    /// type Nested = Coprod!(Coprod!(i32), Coprod!(bool, &'static str), Coprod!(f32));
+    /// type Flat = Coprod!(i32, bool, &'static str, f32);
+    ///
+    /// let co = Nested::inject(<Coprod!(bool, &'static str)>::inject("hi"));
+    /// assert_eq!(co.flatten(), Flat::inject("hi"));
+    ///
+    /// // Mixing plain and nested variants
+    /// type Mixed = Coprod!(i32, Coprod!(bool, &'static str), f32);
+    ///
+    /// let co = Mixed::inject(3);
+    /// let flat: Flat = co
+    ///     .map(hlist![
+    ///         |i| <Coprod!(i32)>::inject(i),
+    ///         |bs| bs,
+    ///         |f| <Coprod!(f32)>::inject(f),
+    ///     ])
+    ///     .flatten();
+    /// assert_eq!(flat, Flat::inject(3));
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn flatten(self) -> <Self as CoproductFlatten>::Output
+    where
+        Self: CoproductFlatten,
+    {
+        CoproductFlatten::flatten(self)
     }
-}
-
-// For turning something into a Coproduct -->
 
-/// Trait for borrowing a coproduct element by type
-///
-/// This trait is part of the implementation of the inherent method
-/// [`Coproduct::get`]. Please see that method for more information.
-///
-/// You only need to import this trait when working with generic
-/// Coproducts of unknown type. If you have a Coproduct of known type,
-/// then `co.get()` should "just work" even without the trait.
-///
-/// [`Coproduct::get`]: enum.Coproduct.html#method.get
-pub trait CoproductSelector<S, I> {
-    /// Borrow an element from a coproduct by type.
+    /// Returns the position of the variant currently held by the coproduct.
     ///
-    /// Please see the [inherent method] for more information.
+    /// Please see [`CoproductVariants::variant_index`] for more information.
     ///
-    /// The only difference between that inherent method and this
-    /// trait method is the location of the type parameters.
-    /// (here, they are on the trait rather than the method)
+    /// # Example
     ///
-    /// [inherent method]: enum.Coproduct.html#method.get
-    fn get(&self) -> Option<&S>;
-}
-
-impl<Head, Tail> CoproductSelector<Head, Here> for Coproduct<Head, Tail> {
-    fn get(&self) -> Option<&Head> {
-        use self::Coproduct::*;
-        match *self {
-            Inl(ref thing) => Some(thing),
-            _ => None, // Impossible
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::Coprod;
+    ///
+    /// type I32BoolF32 = Coprod!(i32, bool, f32);
+    ///
+    /// assert_eq!(I32BoolF32::inject(3).variant_index(), 0);
+    /// assert_eq!(I32BoolF32::inject(42f32).variant_index(), 2);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn variant_index(&self) -> usize
+    where
+        Self: CoproductVariants,
+    {
+        CoproductVariants::variant_index(self)
+    }
+
+    /// Returns the type name of the variant currently held by the coproduct.
+    ///
+    /// Please see [`CoproductVariants::variant_type_name`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::Coprod;
+    ///
+    /// type I32BoolF32 = Coprod!(i32, bool, f32);
+    ///
+    /// assert_eq!(I32BoolF32::inject(true).variant_type_name(), "bool");
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn variant_type_name(&self) -> &'static str
+    where
+        Self: CoproductVariants,
+    {
+        CoproductVariants::variant_type_name(self)
+    }
+
+    /// Borrow the value held by the coproduct as a trait object.
+    ///
+    /// Every variant must be upcastable to `Dyn`, which is expressed through
+    /// [`UpcastFrom`]. Implementations are provided for `dyn Debug`, `dyn Display`
+    /// and `dyn Any`, and [`impl_upcast_from!`](crate::impl_upcast_from) provides
+    /// them for your own traits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::{impl_upcast_from, Coprod};
+    /// use std::fmt::Debug;
+    ///
+    /// trait Shape {
+    ///     fn area(&self) -> f64;
+    /// }
+    ///
+    /// struct Square(f64);
+    /// struct Circle(f64);
+    ///
+    /// impl Shape for Square {
+    ///     fn area(&self) -> f64 {
+    ///         self.0 * self.0
+    ///     }
+    /// }
+    ///
+    /// impl Shape for Circle {
+    ///     fn area(&self) -> f64 {
+    ///         3.0 * self.0 * self.0
+    ///     }
+    /// }
+    ///
+    /// impl_upcast_from!(dyn Shape);
+    ///
+    /// type Shapes = Coprod!(Square, Circle);
+    ///
+    /// let shape = Shapes::inject(Circle(2.0));
+    /// assert_eq!(shape.as_dyn::<dyn Shape>().area(), 12.0);
+    ///
+    /// let co = <Coprod!(i32, &str)>::inject("hi");
+    /// assert_eq!(format!("{:?}", co.as_dyn::<dyn Debug>()), "\"hi\"");
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn as_dyn<Dyn: ?Sized>(&self) -> &Dyn
+    where
+        Self: CoproductAsDyn<Dyn>,
+    {
+        CoproductAsDyn::as_dyn(self)
+    }
+
+    /// Mutably borrow the value held by the coproduct as a trait object.
+    ///
+    /// Please see [`as_dyn`](Coproduct::as_dyn) for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::{impl_upcast_from, Coprod};
+    ///
+    /// trait Counter {
+    ///     fn bump(&mut self);
+    /// }
+    ///
+    /// impl Counter for u8 {
+    ///     fn bump(&mut self) {
+    ///         *self += 1;
+    ///     }
+    /// }
+    ///
+    /// impl Counter for String {
+    ///     fn bump(&mut self) {
+    ///         self.push('!');
+    ///     }
+    /// }
+    ///
+    /// impl_upcast_from!(dyn Counter);
+    ///
+    /// let mut co = <Coprod!(u8, String)>::inject(String::from("hi"));
+    /// co.as_dyn_mut::<dyn Counter>().bump();
+    /// assert_eq!(co.take(), Some(String::from("hi!")));
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn as_dyn_mut<Dyn: ?Sized>(&mut self) -> &mut Dyn
+    where
+        Self: CoproductAsDyn<Dyn>,
+    {
+        CoproductAsDyn::as_dyn_mut(self)
+    }
+
+    /// Collapse a coproduct whose variants all have the same type into that type.
+    ///
+    /// This generalizes [`extract`](Coproduct::extract) to coproducts of any size, and
+    /// is handy after mapping every variant to a common type. To borrow the value
+    /// instead, call it on [`to_ref`](Coproduct::to_ref) or [`to_mut`](Coproduct::to_mut).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::coproduct::Coproduct;
+    /// use frunk_core::{hlist, Coprod};
+    ///
+    /// type I32BoolF32 = Coprod!(i32, bool, f32);
+    ///
+    /// let co = I32BoolF32::inject(true);
+    /// let described = co.map(hlist![
+    ///     |i: i32| format!("int {}", i),
+    ///     |b: bool| format!("bool {}", b),
+    ///     |f: f32| format!("float {}", f),
+    /// ]);
+    /// assert_eq!(described.unify(), "bool true");
+    ///
+    /// // `inject` can't pick between identical variants, so construct it directly
+    /// let mut co = <Coprod!(u8, u8)>::Inr(Coproduct::Inl(3));
+    /// *co.to_mut().unify() += 1;
+    /// assert_eq!(co.to_ref().unify(), &4);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn unify(self) -> Head
+    where
+        Self: CoproductUnify<Head>,
+    {
+        CoproductUnify::unify(self)
+    }
+
+    /// Alias for [`unify`](Coproduct::unify).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::Coprod;
+    ///
+    /// let co = <Coprod!(&str, &str)>::Inl("hello");
+    /// assert_eq!(co.into_inner(), "hello");
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn into_inner(self) -> Head
+    where
+        Self: CoproductUnify<Head>,
+    {
+        CoproductUnify::unify(self)
+    }
+}
+
+impl<T> Coproduct<T, CNil> {
+    /// Extract the value from a coproduct with only one variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk_core::Coprod;
+    ///
+    /// type I32Only = Coprod!(i32);
+    /// let co = I32Only::inject(5);
+    ///
+    /// assert_eq!(co.extract(), 5);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn extract(self) -> T {
+        match self {
+            Coproduct::Inl(v) => v,
+            Coproduct::Inr(never) => match never {},
         }
     }
 }
 
-impl<Head, FromTail, Tail, TailIndex> CoproductSelector<FromTail, There<TailIndex>>
-    for Coproduct<Head, Tail>
+/// Typeclass for inspecting the variants of a Coproduct at runtime.
+///
+/// This is the Coproduct counterpart of the `HList` trait, and is implemented
+/// for `CNil` and every `Coproduct<H, T>`.
+pub trait CoproductVariants {
+    /// Returns the number of variants of a given Coproduct type without making use of any
+    /// values at all.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() {
+    /// use frunk::prelude::*;
+    /// use frunk_core::Coprod;
+    ///
+    /// assert_eq!(<Coprod!(i32, bool, f32)>::LEN, 3);
+    /// # }
+    /// ```
+    const LEN: usize;
+
+    /// Returns the position of the variant currently held by the coproduct,
+    /// counting from 0 at the leftmost type.
+    ///
+    /// This is also available as an [inherent method].
+    ///
+    /// [inherent method]: Coproduct::variant_index
+    fn variant_index(&self) -> usize;
+
+    /// Returns the name of the type of the variant currently held by the coproduct,
+    /// as given by [`core::any::type_name`].
+    ///
+    /// The returned string is meant for diagnostics (e.g. metrics or logs);
+    /// like `type_name` itself, its exact contents are not guaranteed to be stable.
+    ///
+    /// This is also available as an [inherent method].
+    ///
+    /// [inherent method]: Coproduct::variant_type_name
+    fn variant_type_name(&self) -> &'static str;
+}
+
+impl CoproductVariants for CNil {
+    const LEN: usize = 0;
+
+    fn variant_index(&self) -> usize {
+        match *self {}
+    }
+
+    fn variant_type_name(&self) -> &'static str {
+        match *self {}
+    }
+}
+
+impl<Head, Tail> CoproductVariants for Coproduct<Head, Tail>
 where
-    Tail: CoproductSelector<FromTail, TailIndex>,
+    Tail: CoproductVariants,
 {
-    fn get(&self) -> Option<&FromTail> {
-        use self::Coproduct::*;
+    const LEN: usize = 1 + <Tail as CoproductVariants>::LEN;
+
+    #[inline]
+    fn variant_index(&self) -> usize {
         match *self {
-            Inr(ref rest) => rest.get(),
-            _ => None, // Impossible
+            Coproduct::Inl(_) => 0,
+            Coproduct::Inr(ref tail) => 1 + tail.variant_index(),
+        }
+    }
+
+    #[inline]
+    fn variant_type_name(&self) -> &'static str {
+        match *self {
+            Coproduct::Inl(_) => core::any::type_name::<Head>(),
+            Coproduct::Inr(ref tail) => tail.variant_type_name(),
         }
     }
 }
 
-/// Trait for retrieving a coproduct element by type
+/// Trait for instantiating a coproduct from an element
 ///
-/// This trait is part of the implementation of the inherent method
-/// [`Coproduct::take`]. Please see that method for more information.
+/// This trait is part of the implementation of the inherent static method
+/// [`Coproduct::inject`]. Please see that method for more information.
 ///
 /// You only need to import this trait when working with generic
-/// Coproducts of unknown type. If you have a Coproduct of known type,
-/// then `co.take()` should "just work" even without the trait.
+/// Coproducts of unknown type. In most code, `Coproduct::inject` will
+/// "just work," with or without this trait.
 ///
-/// [`Coproduct::take`]: enum.Coproduct.html#method.take
-pub trait CoproductTaker<S, I> {
-    /// Retrieve an element from a coproduct by type, ignoring all others.
+/// [`Coproduct::inject`]: enum.Coproduct.html#method.inject
+pub trait CoprodInjector<InjectType, Index> {
+    /// Instantiate a coproduct from an element.
     ///
-    /// Please see the [inherent method] for more information.
+    /// Please see the [inherent static method] for more information.
     ///
     /// The only difference between that inherent method and this
     /// trait method is the location of the type parameters.
     /// (here, they are on the trait rather than the method)
     ///
-    /// [inherent method]: enum.Coproduct.html#method.take
-    fn take(self) -> Option<S>;
+    /// [inherent static method]: enum.Coproduct.html#method.inject
+    fn inject(to_insert: InjectType) -> Self;
+}
+
+impl<I, Tail> CoprodInjector<I, Here> for Coproduct<I, Tail> {
+    fn inject(to_insert: I) -> Self {
+        Coproduct::Inl(to_insert)
+    }
+}
+
+impl<Head, I, Tail, TailIndex> CoprodInjector<I, There<TailIndex>> for Coproduct<Head, Tail>
+where
+    Tail: CoprodInjector<I, TailIndex>,
+{
+    fn inject(to_insert: I) -> Self {
+        let tail_inserted = <Tail as CoprodInjector<I, TailIndex>>::inject(to_insert);
+        Coproduct::Inr(tail_inserted)
+    }
+}
+
+/// Trait for working with the variant of a coproduct at a type-level position.
+///
+/// `Index` is `Here` for the first variant, and `There<N>` for the variant after
+/// the one at `N`. `Value` is the type of the variant at that position.
+///
+/// This trait is part of the implementation of the inherent methods
+/// [`Coproduct::inject_at`], [`Coproduct::get_at`] and [`Coproduct::take_at`].
+/// Please see those methods for more information.
+pub trait CoprodAt<Index>: Sized {
+    type Value;
+
+    /// Instantiate a coproduct at this position.
+    ///
+    /// Please see the [inherent static method] for more information.
+    ///
+    /// [inherent static method]: Coproduct::inject_at
+    fn inject_at(to_insert: Self::Value) -> Self;
+
+    /// Borrow the element at this position, if present.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: Coproduct::get_at
+    fn get_at(&self) -> Option<&Self::Value>;
+
+    /// Retrieve the element at this position, if present.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: Coproduct::take_at
+    fn take_at(self) -> Option<Self::Value>;
+}
+
+impl<Head, Tail> CoprodAt<Here> for Coproduct<Head, Tail> {
+    type Value = Head;
+
+    #[inline(always)]
+    fn inject_at(to_insert: Head) -> Self {
+        Coproduct::Inl(to_insert)
+    }
+
+    #[inline(always)]
+    fn get_at(&self) -> Option<&Head> {
+        match *self {
+            Coproduct::Inl(ref head) => Some(head),
+            Coproduct::Inr(_) => None,
+        }
+    }
+
+    #[inline(always)]
+    fn take_at(self) -> Option<Head> {
+        match self {
+            Coproduct::Inl(head) => Some(head),
+            Coproduct::Inr(_) => None,
+        }
+    }
+}
+
+impl<Head, Tail, TailIndex> CoprodAt<There<TailIndex>> for Coproduct<Head, Tail>
+where
+    Tail: CoprodAt<TailIndex>,
+{
+    type Value = <Tail as CoprodAt<TailIndex>>::Value;
+
+    #[inline(always)]
+    fn inject_at(to_insert: Self::Value) -> Self {
+        Coproduct::Inr(Tail::inject_at(to_insert))
+    }
+
+    #[inline(always)]
+    fn get_at(&self) -> Option<&Self::Value> {
+        match *self {
+            Coproduct::Inl(_) => None,
+            Coproduct::Inr(ref tail) => tail.get_at(),
+        }
+    }
+
+    #[inline(always)]
+    fn take_at(self) -> Option<Self::Value> {
+        match self {
+            Coproduct::Inl(_) => None,
+            Coproduct::Inr(tail) => tail.take_at(),
+        }
+    }
+}
+
+// For turning something into a Coproduct -->
+
+/// Trait for borrowing a coproduct element by type
+///
+/// This trait is part of the implementation of the inherent method
+/// [`Coproduct::get`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// Coproducts of unknown type. If you have a Coproduct of known type,
+/// then `co.get()` should "just work" even without the trait.
+///
+/// [`Coproduct::get`]: enum.Coproduct.html#method.get
+pub trait CoproductSelector<S, I> {
+    /// Borrow an element from a coproduct by type.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: enum.Coproduct.html#method.get
+    fn get(&self) -> Option<&S>;
+}
+
+impl<Head, Tail> CoproductSelector<Head, Here> for Coproduct<Head, Tail> {
+    fn get(&self) -> Option<&Head> {
+        use self::Coproduct::*;
+        match *self {
+            Inl(ref thing) => Some(thing),
+            _ => None, // Impossible
+        }
+    }
+}
+
+impl<Head, FromTail, Tail, TailIndex> CoproductSelector<FromTail, There<TailIndex>>
+    for Coproduct<Head, Tail>
+where
+    Tail: CoproductSelector<FromTail, TailIndex>,
+{
+    fn get(&self) -> Option<&FromTail> {
+        use self::Coproduct::*;
+        match *self {
+            Inr(ref rest) => rest.get(),
+            _ => None, // Impossible
+        }
+    }
+}
+
+/// Trait for retrieving a coproduct element by type
+///
+/// This trait is part of the implementation of the inherent method
+/// [`Coproduct::take`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// Coproducts of unknown type. If you have a Coproduct of known type,
+/// then `co.take()` should "just work" even without the trait.
+///
+/// [`Coproduct::take`]: enum.Coproduct.html#method.take
+pub trait CoproductTaker<S, I> {
+    /// Retrieve an element from a coproduct by type, ignoring all others.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: enum.Coproduct.html#method.take
+    fn take(self) -> Option<S>;
+}
+
+impl<Head, Tail> CoproductTaker<Head, Here> for Coproduct<Head, Tail> {
+    fn take(self) -> Option<Head> {
+        use self::Coproduct::*;
+        match self {
+            Inl(thing) => Some(thing),
+            _ => None, // Impossible
+        }
+    }
+}
+
+impl<Head, FromTail, Tail, TailIndex> CoproductTaker<FromTail, There<TailIndex>>
+    for Coproduct<Head, Tail>
+where
+    Tail: CoproductTaker<FromTail, TailIndex>,
+{
+    fn take(self) -> Option<FromTail> {
+        use self::Coproduct::*;
+        match self {
+            Inr(rest) => rest.take(),
+            _ => None, // Impossible
+        }
+    }
+}
+
+/// Trait for folding a coproduct into a single value.
+///
+/// This trait is part of the implementation of the inherent method
+/// [`Coproduct::fold`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// Coproducts or Folders of unknown type. If the type of everything is known,
+/// then `co.fold(folder)` should "just work" even without the trait.
+///
+/// [`Coproduct::fold`]: enum.Coproduct.html#method.fold
+pub trait CoproductFoldable<Folder, Output> {
+    /// Use functions to fold a coproduct into a single value.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: enum.Coproduct.html#method.fold
+    fn fold(self, f: Folder) -> Output;
+}
+
+impl<P, R, CH, CTail> CoproductFoldable<Poly<P>, R> for Coproduct<CH, CTail>
+where
+    P: Func<CH, Output = R>,
+    CTail: CoproductFoldable<Poly<P>, R>,
+{
+    fn fold(self, f: Poly<P>) -> R {
+        use self::Coproduct::*;
+        match self {
+            Inl(r) => P::call(r),
+            Inr(rest) => rest.fold(f),
+        }
+    }
+}
+
+impl<F, R, FTail, CH, CTail> CoproductFoldable<HCons<F, FTail>, R> for Coproduct<CH, CTail>
+where
+    F: FnOnce(CH) -> R,
+    CTail: CoproductFoldable<FTail, R>,
+{
+    fn fold(self, f: HCons<F, FTail>) -> R {
+        use self::Coproduct::*;
+        let f_head = f.head;
+        let f_tail = f.tail;
+        match self {
+            Inl(r) => (f_head)(r),
+            Inr(rest) => rest.fold(f_tail),
+        }
+    }
+}
+
+/// This is literally impossible; CNil is not instantiable
+impl<F, R> CoproductFoldable<F, R> for CNil {
+    fn fold(self, _: F) -> R {
+        unreachable!()
+    }
+}
+
+/// Trait for mapping over a coproduct's variants.
+///
+/// This trait is part of the implementation of the inherent method
+/// [`Coproduct::map`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic Coproducts or
+/// mappers of unknown type. If the type of everything is known, then
+/// `co.map(mapper)` should "just work" even without the trait.
+pub trait CoproductMappable<Mapper> {
+    type Output;
+
+    /// Use functions to map each variant of a coproduct.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: Coproduct::map
+    fn map(self, f: Mapper) -> Self::Output;
+}
+
+/// Implementation for mapping a Coproduct using an `hlist!`.
+impl<F, R, MapperTail, CH, CTail> CoproductMappable<HCons<F, MapperTail>> for Coproduct<CH, CTail>
+where
+    F: FnOnce(CH) -> R,
+    CTail: CoproductMappable<MapperTail>,
+{
+    type Output = Coproduct<R, <CTail as CoproductMappable<MapperTail>>::Output>;
+
+    #[inline]
+    fn map(self, mapper: HCons<F, MapperTail>) -> Self::Output {
+        match self {
+            Coproduct::Inl(l) => Coproduct::Inl((mapper.head)(l)),
+            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(mapper.tail)),
+        }
+    }
+}
+
+/// Implementation for mapping a Coproduct using a `&hlist!`.
+impl<'a, F, R, MapperTail, CH, CTail> CoproductMappable<&'a HCons<F, MapperTail>>
+    for Coproduct<CH, CTail>
+where
+    F: Fn(CH) -> R,
+    CTail: CoproductMappable<&'a MapperTail>,
+{
+    type Output = Coproduct<R, <CTail as CoproductMappable<&'a MapperTail>>::Output>;
+
+    #[inline]
+    fn map(self, mapper: &'a HCons<F, MapperTail>) -> Self::Output {
+        match self {
+            Coproduct::Inl(l) => Coproduct::Inl((mapper.head)(l)),
+            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(&mapper.tail)),
+        }
+    }
+}
+
+/// Implementation for mapping a Coproduct using a `&mut hlist!`.
+impl<'a, F, R, MapperTail, CH, CTail> CoproductMappable<&'a mut HCons<F, MapperTail>>
+    for Coproduct<CH, CTail>
+where
+    F: FnMut(CH) -> R,
+    CTail: CoproductMappable<&'a mut MapperTail>,
+{
+    type Output = Coproduct<R, <CTail as CoproductMappable<&'a mut MapperTail>>::Output>;
+
+    #[inline]
+    fn map(self, mapper: &'a mut HCons<F, MapperTail>) -> Self::Output {
+        match self {
+            Coproduct::Inl(l) => Coproduct::Inl((mapper.head)(l)),
+            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(&mut mapper.tail)),
+        }
+    }
+}
+
+/// Implementation for mapping a Coproduct using a `poly_fn!`.
+impl<P, CH, CTail> CoproductMappable<Poly<P>> for Coproduct<CH, CTail>
+where
+    P: Func<CH>,
+    CTail: CoproductMappable<Poly<P>>,
+{
+    type Output = Coproduct<<P as Func<CH>>::Output, <CTail as CoproductMappable<Poly<P>>>::Output>;
+
+    #[inline]
+    fn map(self, poly: Poly<P>) -> Self::Output {
+        match self {
+            Coproduct::Inl(l) => Coproduct::Inl(P::call(l)),
+            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(poly)),
+        }
+    }
+}
+
+/// Implementation for mapping a Coproduct using a `&poly_fn!`.
+impl<'a, P, CH, CTail> CoproductMappable<&'a Poly<P>> for Coproduct<CH, CTail>
+where
+    P: Func<CH>,
+    CTail: CoproductMappable<&'a Poly<P>>,
+{
+    type Output =
+        Coproduct<<P as Func<CH>>::Output, <CTail as CoproductMappable<&'a Poly<P>>>::Output>;
+
+    #[inline]
+    fn map(self, poly: &'a Poly<P>) -> Self::Output {
+        match self {
+            Coproduct::Inl(l) => Coproduct::Inl(P::call(l)),
+            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(poly)),
+        }
+    }
+}
+
+/// Implementation for mapping a Coproduct using a `&mut poly_fn!`.
+impl<'a, P, CH, CTail> CoproductMappable<&'a mut Poly<P>> for Coproduct<CH, CTail>
+where
+    P: Func<CH>,
+    CTail: CoproductMappable<&'a mut Poly<P>>,
+{
+    type Output =
+        Coproduct<<P as Func<CH>>::Output, <CTail as CoproductMappable<&'a mut Poly<P>>>::Output>;
+
+    #[inline]
+    fn map(self, poly: &'a mut Poly<P>) -> Self::Output {
+        match self {
+            Coproduct::Inl(l) => Coproduct::Inl(P::call(l)),
+            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(poly)),
+        }
+    }
+}
+
+/// Implementation for mapping a Coproduct using a single function that can
+/// handle all variants.
+impl<F, R, CH, CTail> CoproductMappable<F> for Coproduct<CH, CTail>
+where
+    F: FnMut(CH) -> R,
+    CTail: CoproductMappable<F>,
+{
+    type Output = Coproduct<R, <CTail as CoproductMappable<F>>::Output>;
+
+    #[inline]
+    fn map(self, mut f: F) -> Self::Output {
+        match self {
+            Coproduct::Inl(l) => Coproduct::Inl(f(l)),
+            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(f)),
+        }
+    }
+}
+
+/// Base case map impl.
+impl<F> CoproductMappable<F> for CNil {
+    type Output = CNil;
+
+    #[inline(always)]
+    fn map(self, _: F) -> Self::Output {
+        match self {}
+    }
+}
+
+impl<'a, CH: 'a, CTail> ToRef<'a> for Coproduct<CH, CTail>
+where
+    CTail: ToRef<'a>,
+{
+    type Output = Coproduct<&'a CH, <CTail as ToRef<'a>>::Output>;
+
+    #[inline(always)]
+    fn to_ref(&'a self) -> Self::Output {
+        match *self {
+            Coproduct::Inl(ref r) => Coproduct::Inl(r),
+            Coproduct::Inr(ref rest) => Coproduct::Inr(rest.to_ref()),
+        }
+    }
+}
+
+impl<'a> ToRef<'a> for CNil {
+    type Output = CNil;
+
+    fn to_ref(&'a self) -> CNil {
+        match *self {}
+    }
+}
+
+impl<'a, CH: 'a, CTail> ToMut<'a> for Coproduct<CH, CTail>
+where
+    CTail: ToMut<'a>,
+{
+    type Output = Coproduct<&'a mut CH, <CTail as ToMut<'a>>::Output>;
+
+    #[inline(always)]
+    fn to_mut(&'a mut self) -> Self::Output {
+        match *self {
+            Coproduct::Inl(ref mut r) => Coproduct::Inl(r),
+            Coproduct::Inr(ref mut rest) => Coproduct::Inr(rest.to_mut()),
+        }
+    }
+}
+
+impl<'a> ToMut<'a> for CNil {
+    type Output = CNil;
+
+    fn to_mut(&'a mut self) -> CNil {
+        match *self {}
+    }
+}
+
+/// Trait for extracting a value from a coproduct in an exhaustive way.
+///
+/// This trait is part of the implementation of the inherent method
+/// [`Coproduct::uninject`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// Coproducts of unknown type. If you have a Coproduct of known type,
+/// then `co.uninject()` should "just work" even without the trait.
+///
+/// [`Coproduct::uninject`]: enum.Coproduct.html#method.uninject
+pub trait CoprodUninjector<T, Idx>: CoprodInjector<T, Idx> {
+    type Remainder;
+
+    /// Attempt to extract a value from a coproduct (or get the remaining possibilities).
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: enum.Coproduct.html#method.uninject
+    fn uninject(self) -> Result<T, Self::Remainder>;
+}
+
+impl<Hd, Tl> CoprodUninjector<Hd, Here> for Coproduct<Hd, Tl> {
+    type Remainder = Tl;
+
+    fn uninject(self) -> Result<Hd, Tl> {
+        match self {
+            Coproduct::Inl(h) => Ok(h),
+            Coproduct::Inr(t) => Err(t),
+        }
+    }
+}
+
+impl<Hd, Tl, T, N> CoprodUninjector<T, There<N>> for Coproduct<Hd, Tl>
+where
+    Tl: CoprodUninjector<T, N>,
+{
+    type Remainder = Coproduct<Hd, Tl::Remainder>;
+
+    fn uninject(self) -> Result<T, Self::Remainder> {
+        match self {
+            Coproduct::Inl(h) => Err(Coproduct::Inl(h)),
+            Coproduct::Inr(t) => t.uninject().map_err(Coproduct::Inr),
+        }
+    }
+}
+
+/// Trait for extracting a subset of the possible types in a coproduct.
+///
+/// This trait is part of the implementation of the inherent method
+/// [`Coproduct::subset`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// Coproducts of unknown type. If you have a Coproduct of known type,
+/// then `co.subset()` should "just work" even without the trait.
+///
+/// [`Coproduct::subset`]: enum.Coproduct.html#method.subset
+pub trait CoproductSubsetter<Targets, Indices>: Sized {
+    type Remainder;
+
+    /// Extract a subset of the possible types in a coproduct (or get the remaining possibilities)
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: enum.Coproduct.html#method.subset
+    fn subset(self) -> Result<Targets, Self::Remainder>;
+}
+
+impl<Choices, THead, TTail, NHead, NTail, Rem>
+    CoproductSubsetter<Coproduct<THead, TTail>, HCons<NHead, NTail>> for Choices
+where
+    Self: CoprodUninjector<THead, NHead, Remainder = Rem>,
+    Rem: CoproductSubsetter<TTail, NTail>,
+{
+    type Remainder = <Rem as CoproductSubsetter<TTail, NTail>>::Remainder;
+
+    /// Attempt to extract a value from a subset of the types.
+    fn subset(self) -> Result<Coproduct<THead, TTail>, Self::Remainder> {
+        match self.uninject() {
+            Ok(good) => Ok(Coproduct::Inl(good)),
+            Err(bads) => match bads.subset() {
+                Ok(goods) => Ok(Coproduct::Inr(goods)),
+                Err(bads) => Err(bads),
+            },
+        }
+    }
+}
+
+impl<Choices> CoproductSubsetter<CNil, HNil> for Choices {
+    type Remainder = Self;
+
+    #[inline(always)]
+    fn subset(self) -> Result<CNil, Self::Remainder> {
+        Err(self)
+    }
+}
+
+/// Trait for converting a coproduct into another that can hold its variants.
+///
+/// This trait is part of the implementation of the inherent method
+/// [`Coproduct::embed`]. Please see that method for more information.
+///
+/// You only need to import this trait when working with generic
+/// Coproducts of unknown type. If you have a Coproduct of known type,
+/// then `co.embed()` should "just work" even without the trait.
+///
+/// [`Coproduct::embed`]: enum.Coproduct.html#method.embed
+pub trait CoproductEmbedder<Out, Indices> {
+    /// Convert a coproduct into another that can hold its variants.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// The only difference between that inherent method and this
+    /// trait method is the location of the type parameters.
+    /// (here, they are on the trait rather than the method)
+    ///
+    /// [inherent method]: enum.Coproduct.html#method.embed
+    fn embed(self) -> Out;
+}
+
+impl CoproductEmbedder<CNil, HNil> for CNil {
+    fn embed(self) -> CNil {
+        match self {
+        // impossible!
+    }
+    }
+}
+
+impl<Head, Tail> CoproductEmbedder<Coproduct<Head, Tail>, HNil> for CNil
+where
+    CNil: CoproductEmbedder<Tail, HNil>,
+{
+    fn embed(self) -> Coproduct<Head, Tail> {
+        match self {
+        // impossible!
+    }
+    }
+}
+
+impl<Head, Tail, Out, NHead, NTail> CoproductEmbedder<Out, HCons<NHead, NTail>>
+    for Coproduct<Head, Tail>
+where
+    Out: CoprodInjector<Head, NHead>,
+    Tail: CoproductEmbedder<Out, NTail>,
+{
+    fn embed(self) -> Out {
+        match self {
+            Coproduct::Inl(this) => Out::inject(this),
+            Coproduct::Inr(those) => those.embed(),
+        }
+    }
+}
+
+/// Trait for appending the variants of one coproduct type to another.
+///
+/// `Output` is the type-level concatenation of `Self` followed by `Right`,
+/// analogous to the `Add` impl for HLists. For example,
+/// `<Coprod!(A, B) as CoprodAppend<Coprod!(C)>>::Output` is `Coprod!(A, B, C)`.
+///
+/// This trait is part of the implementation of the inherent methods
+/// [`Coproduct::extend_left`] and [`Coproduct::extend_right`]. Please see
+/// those methods for more information.
+pub trait CoprodAppend<Right> {
+    type Output;
+
+    /// Inject a value of the left-hand coproduct into the concatenated coproduct.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: Coproduct::extend_right
+    fn extend_right(self) -> Self::Output;
+
+    /// Inject a value of the right-hand coproduct into the concatenated coproduct.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: Coproduct::extend_left
+    fn extend_left(right: Right) -> Self::Output;
+
+    /// Split a value of the concatenated coproduct back into whichever of the
+    /// left-hand (`Ok`) or right-hand (`Err`) coproducts it came from.
+    fn split(combined: Self::Output) -> Result<Self, Right>
+    where
+        Self: Sized;
+}
+
+impl<Right> CoprodAppend<Right> for CNil {
+    type Output = Right;
+
+    #[inline(always)]
+    fn extend_right(self) -> Right {
+        match self {}
+    }
+
+    #[inline(always)]
+    fn extend_left(right: Right) -> Right {
+        right
+    }
+
+    #[inline(always)]
+    fn split(combined: Right) -> Result<Self, Right> {
+        Err(combined)
+    }
+}
+
+impl<Head, Tail, Right> CoprodAppend<Right> for Coproduct<Head, Tail>
+where
+    Tail: CoprodAppend<Right>,
+{
+    type Output = Coproduct<Head, <Tail as CoprodAppend<Right>>::Output>;
+
+    #[inline(always)]
+    fn extend_right(self) -> Self::Output {
+        match self {
+            Coproduct::Inl(head) => Coproduct::Inl(head),
+            Coproduct::Inr(tail) => Coproduct::Inr(tail.extend_right()),
+        }
+    }
+
+    #[inline(always)]
+    fn extend_left(right: Right) -> Self::Output {
+        Coproduct::Inr(Tail::extend_left(right))
+    }
+
+    #[inline(always)]
+    fn split(combined: Self::Output) -> Result<Self, Right> {
+        match combined {
+            Coproduct::Inl(head) => Ok(Coproduct::Inl(head)),
+            Coproduct::Inr(tail) => Tail::split(tail).map(Coproduct::Inr),
+        }
+    }
+}
+
+/// Trait for distributing a product over sums: turning an HList of coproducts
+/// into a coproduct of HLists, one variant for every combination of choices.
+///
+/// For example, `HList![Coprod!(A), Coprod!(B, C)]` distributes to
+/// `Coprod!(HList![A, B], HList![A, C])`. Factors that are not a choice are written
+/// as single-variant coproducts, like `Coprod!(A)` here. Variants are ordered with
+/// the choices of the first factor varying slowest.
+///
+/// Since every variant records which choice was made for each factor, the
+/// distribution can be reversed with [`undistribute`](Distribute::undistribute).
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// use frunk::prelude::*;
+/// use frunk::Coproduct;
+/// use frunk_core::{hlist, hlist_pat, Coprod, HList};
+///
+/// type Request = HList![Coprod!(&'static str), Coprod!(u32, bool)];
+/// type Cases = Coprod!(HList![&'static str, u32], HList![&'static str, bool]);
+///
+/// let request: Request = hlist![Coproduct::inject("GET"), Coproduct::inject(true)];
+/// let cases: Cases = request.distribute();
+/// assert_eq!(cases, Cases::inject(hlist!["GET", true]));
+///
+/// // Every combination can now be handled exhaustively
+/// let described = cases.fold(hlist![
+///     |hlist_pat![method, id]: HList![&str, u32]| format!("{} #{}", method, id),
+///     |hlist_pat![method, all]: HList![&str, bool]| format!("{} all={}", method, all),
+/// ]);
+/// assert_eq!(described, "GET all=true");
+///
+/// assert_eq!(Request::undistribute(cases), request);
+/// # }
+/// ```
+pub trait Distribute: Sized {
+    type Output;
+
+    /// Distribute the HList over the coproducts it contains.
+    fn distribute(self) -> Self::Output;
+
+    /// Reverse [`distribute`](Distribute::distribute).
+    fn undistribute(distributed: Self::Output) -> Self;
+}
+
+impl Distribute for HNil {
+    type Output = Coproduct<HNil, CNil>;
+
+    #[inline(always)]
+    fn distribute(self) -> Self::Output {
+        Coproduct::Inl(self)
+    }
+
+    #[inline(always)]
+    fn undistribute(distributed: Self::Output) -> Self {
+        distributed.extract()
+    }
+}
+
+impl<Head, Tail> Distribute for HCons<Head, Tail>
+where
+    Tail: Distribute,
+    Head: DistributeOver<<Tail as Distribute>::Output>,
+{
+    type Output = <Head as DistributeOver<<Tail as Distribute>::Output>>::Output;
+
+    #[inline(always)]
+    fn distribute(self) -> Self::Output {
+        self.head.distribute_over(self.tail.distribute())
+    }
+
+    #[inline(always)]
+    fn undistribute(distributed: Self::Output) -> Self {
+        let (head, tail) = Head::undistribute_over(distributed);
+        HCons {
+            head,
+            tail: Tail::undistribute(tail),
+        }
+    }
+}
+
+/// Trait for pairing the present variant of a coproduct with the present variant
+/// of a coproduct of HLists, by prepending it.
+///
+/// This trait is part of the implementation of [`Distribute`].
+pub trait DistributeOver<Rest>: Sized {
+    type Output;
+
+    /// Prepend the present variant of `self` to the HList present in `rest`.
+    fn distribute_over(self, rest: Rest) -> Self::Output;
+
+    /// Reverse [`distribute_over`](DistributeOver::distribute_over).
+    fn undistribute_over(distributed: Self::Output) -> (Self, Rest);
+}
+
+impl<Rest> DistributeOver<Rest> for CNil {
+    type Output = CNil;
+
+    fn distribute_over(self, _: Rest) -> CNil {
+        match self {}
+    }
+
+    fn undistribute_over(distributed: CNil) -> (Self, Rest) {
+        match distributed {}
+    }
+}
+
+impl<Head, Tail, Rest> DistributeOver<Rest> for Coproduct<Head, Tail>
+where
+    Rest: ConsEach<Head>,
+    Tail: DistributeOver<Rest>,
+    <Rest as ConsEach<Head>>::Output: CoprodAppend<<Tail as DistributeOver<Rest>>::Output>,
+{
+    type Output = <<Rest as ConsEach<Head>>::Output as CoprodAppend<
+        <Tail as DistributeOver<Rest>>::Output,
+    >>::Output;
+
+    #[inline(always)]
+    fn distribute_over(self, rest: Rest) -> Self::Output {
+        match self {
+            Coproduct::Inl(head) => rest.cons_each(head).extend_right(),
+            Coproduct::Inr(tail) => {
+                <<Rest as ConsEach<Head>>::Output as CoprodAppend<_>>::extend_left(
+                    tail.distribute_over(rest),
+                )
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn undistribute_over(distributed: Self::Output) -> (Self, Rest) {
+        match <<Rest as ConsEach<Head>>::Output as CoprodAppend<_>>::split(distributed) {
+            Ok(consed) => {
+                let (head, rest) = Rest::uncons_each(consed);
+                (Coproduct::Inl(head), rest)
+            }
+            Err(tail) => {
+                let (tail, rest) = Tail::undistribute_over(tail);
+                (Coproduct::Inr(tail), rest)
+            }
+        }
+    }
+}
+
+/// Trait for prepending an element to every variant of a coproduct of HLists.
+///
+/// For example, `<Coprod!(HList![A], HList![B]) as ConsEach<X>>::Output` is
+/// `Coprod!(HList![X, A], HList![X, B])`.
+///
+/// This trait is part of the implementation of [`Distribute`].
+pub trait ConsEach<Head>: Sized {
+    type Output;
+
+    /// Prepend `head` to the HList held by the coproduct.
+    fn cons_each(self, head: Head) -> Self::Output;
+
+    /// Reverse [`cons_each`](ConsEach::cons_each).
+    fn uncons_each(consed: Self::Output) -> (Head, Self);
+}
+
+impl<Head> ConsEach<Head> for CNil {
+    type Output = CNil;
+
+    fn cons_each(self, _: Head) -> CNil {
+        match self {}
+    }
+
+    fn uncons_each(consed: CNil) -> (Head, Self) {
+        match consed {}
+    }
+}
+
+impl<Head, L, Tail> ConsEach<Head> for Coproduct<L, Tail>
+where
+    Tail: ConsEach<Head>,
+{
+    type Output = Coproduct<HCons<Head, L>, <Tail as ConsEach<Head>>::Output>;
+
+    #[inline(always)]
+    fn cons_each(self, head: Head) -> Self::Output {
+        match self {
+            Coproduct::Inl(tail) => Coproduct::Inl(HCons { head, tail }),
+            Coproduct::Inr(rest) => Coproduct::Inr(rest.cons_each(head)),
+        }
+    }
+
+    #[inline(always)]
+    fn uncons_each(consed: Self::Output) -> (Head, Self) {
+        match consed {
+            Coproduct::Inl(HCons { head, tail }) => (head, Coproduct::Inl(tail)),
+            Coproduct::Inr(rest) => {
+                let (head, rest) = Tail::uncons_each(rest);
+                (head, Coproduct::Inr(rest))
+            }
+        }
+    }
+}
+
+/// Trait for flattening a coproduct of coproducts into a single coproduct.
+///
+/// This trait is part of the implementation of the inherent method
+/// [`Coproduct::flatten`]. Please see that method for more information.
+pub trait CoproductFlatten {
+    type Output;
+
+    /// Flatten a coproduct of coproducts into a single coproduct.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: Coproduct::flatten
+    fn flatten(self) -> Self::Output;
+}
+
+impl CoproductFlatten for CNil {
+    type Output = CNil;
+
+    #[inline(always)]
+    fn flatten(self) -> CNil {
+        match self {}
+    }
+}
+
+impl<Head, Tail> CoproductFlatten for Coproduct<Head, Tail>
+where
+    Tail: CoproductFlatten,
+    Head: CoprodAppend<<Tail as CoproductFlatten>::Output>,
+{
+    type Output = <Head as CoprodAppend<<Tail as CoproductFlatten>::Output>>::Output;
+
+    #[inline(always)]
+    fn flatten(self) -> Self::Output {
+        match self {
+            Coproduct::Inl(head) => head.extend_right(),
+            Coproduct::Inr(tail) => Head::extend_left(tail.flatten()),
+        }
+    }
+}
+
+/// Extension trait for lifting the error of a `Result` into a Coproduct.
+///
+/// This lets error unions compose across layers without writing
+/// `map_err(Coproduct::inject)` or `map_err(Coproduct::embed)` everywhere.
+///
+/// The trait is available through `frunk::prelude`.
+pub trait CoproductResultExt<T, E>: Sized {
+    /// Inject the error of a `Result` into a Coproduct that can hold it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk::prelude::*;
+    /// use frunk_core::Coprod;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct ParseError;
+    /// #[derive(Debug, PartialEq)]
+    /// struct IoError;
+    ///
+    /// type AppError = Coprod!(ParseError, IoError);
+    ///
+    /// fn read() -> Result<String, IoError> {
+    ///     Err(IoError)
+    /// }
+    ///
+    /// fn parse(s: &str) -> Result<i32, ParseError> {
+    ///     s.parse().map_err(|_| ParseError)
+    /// }
+    ///
+    /// fn run() -> Result<i32, AppError> {
+    ///     let s = read().inject_err()?;
+    ///     parse(&s).inject_err()
+    /// }
+    ///
+    /// assert_eq!(run(), Err(AppError::inject(IoError)));
+    /// # }
+    /// ```
+    fn inject_err<Out, Index>(self) -> Result<T, Out>
+    where
+        Out: CoprodInjector<E, Index>;
+
+    /// Embed the Coproduct error of a `Result` into a wider Coproduct.
+    ///
+    /// See [`Coproduct::embed`] for the rules governing which conversions are allowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk::prelude::*;
+    /// use frunk_core::Coprod;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct ParseError;
+    /// #[derive(Debug, PartialEq)]
+    /// struct IoError;
+    /// #[derive(Debug, PartialEq)]
+    /// struct AuthError;
+    ///
+    /// fn load() -> Result<i32, Coprod!(IoError, ParseError)> {
+    ///     Err(<Coprod!(IoError, ParseError)>::inject(ParseError))
+    /// }
+    ///
+    /// fn handle() -> Result<i32, Coprod!(AuthError, ParseError, IoError)> {
+    ///     let n = load().embed_err()?;
+    ///     Ok(n + 1)
+    /// }
+    ///
+    /// assert_eq!(
+    ///     handle(),
+    ///     Err(<Coprod!(AuthError, ParseError, IoError)>::inject(ParseError))
+    /// );
+    /// # }
+    /// ```
+    fn embed_err<Out, Indices>(self) -> Result<T, Out>
+    where
+        E: CoproductEmbedder<Out, Indices>;
+}
+
+impl<T, E> CoproductResultExt<T, E> for Result<T, E> {
+    #[inline(always)]
+    fn inject_err<Out, Index>(self) -> Result<T, Out>
+    where
+        Out: CoprodInjector<E, Index>,
+    {
+        self.map_err(Out::inject)
+    }
+
+    #[inline(always)]
+    fn embed_err<Out, Indices>(self) -> Result<T, Out>
+    where
+        E: CoproductEmbedder<Out, Indices>,
+    {
+        self.map_err(CoproductEmbedder::embed)
+    }
 }
 
-impl<Head, Tail> CoproductTaker<Head, Here> for Coproduct<Head, Tail> {
-    fn take(self) -> Option<Head> {
-        use self::Coproduct::*;
-        match self {
-            Inl(thing) => Some(thing),
-            _ => None, // Impossible
-        }
+/// Trait for turning a coproduct of `Result`s sharing an error type into a
+/// `Result` of a coproduct.
+///
+/// For example, `Coprod!(Result<A, E>, Result<B, E>)` becomes
+/// `Result<Coprod!(A, B), E>`.
+///
+/// This trait is part of the implementation of the inherent method
+/// [`Coproduct::try_map`]. Please see that method for more information.
+pub trait CoproductTranspose<E> {
+    type Ok;
+
+    /// Move the `Result` of the present variant to the outside of the coproduct.
+    fn transpose(self) -> Result<Self::Ok, E>;
+}
+
+impl<E> CoproductTranspose<E> for CNil {
+    type Ok = CNil;
+
+    fn transpose(self) -> Result<CNil, E> {
+        match self {}
     }
 }
 
-impl<Head, FromTail, Tail, TailIndex> CoproductTaker<FromTail, There<TailIndex>>
-    for Coproduct<Head, Tail>
+impl<T, E, Tail> CoproductTranspose<E> for Coproduct<Result<T, E>, Tail>
 where
-    Tail: CoproductTaker<FromTail, TailIndex>,
+    Tail: CoproductTranspose<E>,
 {
-    fn take(self) -> Option<FromTail> {
-        use self::Coproduct::*;
+    type Ok = Coproduct<T, <Tail as CoproductTranspose<E>>::Ok>;
+
+    #[inline(always)]
+    fn transpose(self) -> Result<Self::Ok, E> {
         match self {
-            Inr(rest) => rest.take(),
-            _ => None, // Impossible
+            Coproduct::Inl(head) => head.map(Coproduct::Inl),
+            Coproduct::Inr(tail) => tail.transpose().map(Coproduct::Inr),
         }
     }
 }
 
-/// Trait for folding a coproduct into a single value.
+/// Trait for collapsing a coproduct whose variants are all `T` into a `T`.
 ///
-/// This trait is part of the implementation of the inherent method
-/// [`Coproduct::fold`]. Please see that method for more information.
-///
-/// You only need to import this trait when working with generic
-/// Coproducts or Folders of unknown type. If the type of everything is known,
-/// then `co.fold(folder)` should "just work" even without the trait.
+/// `CNil` implements this for every `T`, since it has no variants to disagree.
 ///
-/// [`Coproduct::fold`]: enum.Coproduct.html#method.fold
-pub trait CoproductFoldable<Folder, Output> {
-    /// Use functions to fold a coproduct into a single value.
+/// This trait is part of the implementation of the inherent method
+/// [`Coproduct::unify`]. Please see that method for more information.
+pub trait CoproductUnify<T> {
+    /// Return the value of the present variant.
     ///
     /// Please see the [inherent method] for more information.
     ///
-    /// The only difference between that inherent method and this
-    /// trait method is the location of the type parameters.
-    /// (here, they are on the trait rather than the method)
-    ///
-    /// [inherent method]: enum.Coproduct.html#method.fold
-    fn fold(self, f: Folder) -> Output;
+    /// [inherent method]: Coproduct::unify
+    fn unify(self) -> T;
 }
 
-impl<P, R, CH, CTail> CoproductFoldable<Poly<P>, R> for Coproduct<CH, CTail>
-where
-    P: Func<CH, Output = R>,
-    CTail: CoproductFoldable<Poly<P>, R>,
-{
-    fn fold(self, f: Poly<P>) -> R {
-        use self::Coproduct::*;
-        match self {
-            Inl(r) => P::call(r),
-            Inr(rest) => rest.fold(f),
-        }
+impl<T> CoproductUnify<T> for CNil {
+    fn unify(self) -> T {
+        match self {}
     }
 }
 
-impl<F, R, FTail, CH, CTail> CoproductFoldable<HCons<F, FTail>, R> for Coproduct<CH, CTail>
+impl<T, Tail> CoproductUnify<T> for Coproduct<T, Tail>
 where
-    F: FnOnce(CH) -> R,
-    CTail: CoproductFoldable<FTail, R>,
+    Tail: CoproductUnify<T>,
 {
-    fn fold(self, f: HCons<F, FTail>) -> R {
-        use self::Coproduct::*;
-        let f_head = f.head;
-        let f_tail = f.tail;
+    #[inline(always)]
+    fn unify(self) -> T {
         match self {
-            Inl(r) => (f_head)(r),
-            Inr(rest) => rest.fold(f_tail),
+            Coproduct::Inl(head) => head,
+            Coproduct::Inr(tail) => tail.unify(),
         }
     }
 }
 
-/// This is literally impossible; CNil is not instantiable
-impl<F, R> CoproductFoldable<F, R> for CNil {
-    fn fold(self, _: F) -> R {
-        unreachable!()
+/// Trait for unsized types, usually trait objects, that a reference to `T` can be
+/// upcast to.
+///
+/// Stable Rust has no way to write "any `T` that coerces to `Dyn`" as a bound, so
+/// this trait spells the coercion out. It is implemented on the trait object rather
+/// than on `T`, which lets downstream crates implement it for their own traits; the
+/// [`impl_upcast_from!`](crate::impl_upcast_from) macro does so for every
+/// implementor of a trait at once.
+///
+/// This trait is part of the implementation of the inherent methods
+/// [`Coproduct::as_dyn`] and [`Coproduct::as_dyn_mut`].
+pub trait UpcastFrom<T> {
+    /// Upcast a reference to `T`.
+    fn upcast_ref(value: &T) -> &Self;
+
+    /// Upcast a mutable reference to `T`.
+    fn upcast_mut(value: &mut T) -> &mut Self;
+}
+
+impl_upcast_from!(dyn core::fmt::Debug);
+impl_upcast_from!(dyn core::fmt::Display);
+
+impl<T: Any> UpcastFrom<T> for dyn Any {
+    fn upcast_ref(value: &T) -> &Self {
+        value
+    }
+
+    fn upcast_mut(value: &mut T) -> &mut Self {
+        value
     }
 }
 
-/// Trait for mapping over a coproduct's variants.
-///
-/// This trait is part of the implementation of the inherent method
-/// [`Coproduct::map`]. Please see that method for more information.
+/// Trait for borrowing whichever variant of a coproduct is present as a trait object.
 ///
-/// You only need to import this trait when working with generic Coproducts or
-/// mappers of unknown type. If the type of everything is known, then
-/// `co.map(mapper)` should "just work" even without the trait.
-pub trait CoproductMappable<Mapper> {
-    type Output;
-
-    /// Use functions to map each variant of a coproduct.
+/// This trait is part of the implementation of the inherent methods
+/// [`Coproduct::as_dyn`] and [`Coproduct::as_dyn_mut`]. Please see those methods
+/// for more information.
+pub trait CoproductAsDyn<Dyn: ?Sized> {
+    /// Borrow the present variant as `Dyn`.
     ///
     /// Please see the [inherent method] for more information.
     ///
-    /// The only difference between that inherent method and this
-    /// trait method is the location of the type parameters.
-    /// (here, they are on the trait rather than the method)
+    /// [inherent method]: Coproduct::as_dyn
+    fn as_dyn(&self) -> &Dyn;
+
+    /// Mutably borrow the present variant as `Dyn`.
     ///
-    /// [inherent method]: Coproduct::map
-    fn map(self, f: Mapper) -> Self::Output;
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: Coproduct::as_dyn_mut
+    fn as_dyn_mut(&mut self) -> &mut Dyn;
 }
 
-/// Implementation for mapping a Coproduct using an `hlist!`.
-impl<F, R, MapperTail, CH, CTail> CoproductMappable<HCons<F, MapperTail>> for Coproduct<CH, CTail>
-where
-    F: FnOnce(CH) -> R,
-    CTail: CoproductMappable<MapperTail>,
-{
-    type Output = Coproduct<R, <CTail as CoproductMappable<MapperTail>>::Output>;
+impl<Dyn: ?Sized> CoproductAsDyn<Dyn> for CNil {
+    fn as_dyn(&self) -> &Dyn {
+        match *self {}
+    }
 
-    #[inline]
-    fn map(self, mapper: HCons<F, MapperTail>) -> Self::Output {
-        match self {
-            Coproduct::Inl(l) => Coproduct::Inl((mapper.head)(l)),
-            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(mapper.tail)),
-        }
+    fn as_dyn_mut(&mut self) -> &mut Dyn {
+        match *self {}
     }
 }
 
-/// Implementation for mapping a Coproduct using a `&hlist!`.
-impl<'a, F, R, MapperTail, CH, CTail> CoproductMappable<&'a HCons<F, MapperTail>>
-    for Coproduct<CH, CTail>
+impl<Dyn: ?Sized, Head, Tail> CoproductAsDyn<Dyn> for Coproduct<Head, Tail>
 where
-    F: Fn(CH) -> R,
-    CTail: CoproductMappable<&'a MapperTail>,
+    Dyn: UpcastFrom<Head>,
+    Tail: CoproductAsDyn<Dyn>,
 {
-    type Output = Coproduct<R, <CTail as CoproductMappable<&'a MapperTail>>::Output>;
+    #[inline(always)]
+    fn as_dyn(&self) -> &Dyn {
+        match *self {
+            Coproduct::Inl(ref head) => Dyn::upcast_ref(head),
+            Coproduct::Inr(ref tail) => tail.as_dyn(),
+        }
+    }
 
-    #[inline]
-    fn map(self, mapper: &'a HCons<F, MapperTail>) -> Self::Output {
-        match self {
-            Coproduct::Inl(l) => Coproduct::Inl((mapper.head)(l)),
-            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(&mapper.tail)),
+    #[inline(always)]
+    fn as_dyn_mut(&mut self) -> &mut Dyn {
+        match *self {
+            Coproduct::Inl(ref mut head) => Dyn::upcast_mut(head),
+            Coproduct::Inr(ref mut tail) => tail.as_dyn_mut(),
         }
     }
 }
 
-/// Implementation for mapping a Coproduct using a `&mut hlist!`.
-impl<'a, F, R, MapperTail, CH, CTail> CoproductMappable<&'a mut HCons<F, MapperTail>>
-    for Coproduct<CH, CTail>
-where
-    F: FnMut(CH) -> R,
-    CTail: CoproductMappable<&'a mut MapperTail>,
-{
-    type Output = Coproduct<R, <CTail as CoproductMappable<&'a mut MapperTail>>::Output>;
+/// Trait for converting between a coproduct and a boxed dynamic value.
+///
+/// This trait is part of the implementation of the inherent methods
+/// [`Coproduct::try_from_any`] and [`Coproduct::into_any`]. Please see those
+/// methods for more information.
+#[cfg(feature = "alloc")]
+pub trait CoproductAny: Sized {
+    /// Downcast a boxed value into the first variant with a matching type.
+    ///
+    /// Please see the [inherent static method] for more information.
+    ///
+    /// [inherent static method]: Coproduct::try_from_any
+    fn try_from_any(boxed: Box<dyn Any>) -> Result<Self, Box<dyn Any>>;
 
-    #[inline]
-    fn map(self, mapper: &'a mut HCons<F, MapperTail>) -> Self::Output {
-        match self {
-            Coproduct::Inl(l) => Coproduct::Inl((mapper.head)(l)),
-            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(&mut mapper.tail)),
-        }
-    }
+    /// Box the present variant as a dynamic value.
+    ///
+    /// Please see the [inherent method] for more information.
+    ///
+    /// [inherent method]: Coproduct::into_any
+    fn into_any(self) -> Box<dyn Any>;
 }
 
-/// Implementation for mapping a Coproduct using a `poly_fn!`.
-impl<P, CH, CTail> CoproductMappable<Poly<P>> for Coproduct<CH, CTail>
-where
-    P: Func<CH>,
-    CTail: CoproductMappable<Poly<P>>,
-{
-    type Output = Coproduct<<P as Func<CH>>::Output, <CTail as CoproductMappable<Poly<P>>>::Output>;
+#[cfg(feature = "alloc")]
+impl CoproductAny for CNil {
+    fn try_from_any(boxed: Box<dyn Any>) -> Result<Self, Box<dyn Any>> {
+        Err(boxed)
+    }
 
-    #[inline]
-    fn map(self, poly: Poly<P>) -> Self::Output {
-        match self {
-            Coproduct::Inl(l) => Coproduct::Inl(P::call(l)),
-            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(poly)),
-        }
+    fn into_any(self) -> Box<dyn Any> {
+        match self {}
     }
 }
 
-/// Implementation for mapping a Coproduct using a `&poly_fn!`.
-impl<'a, P, CH, CTail> CoproductMappable<&'a Poly<P>> for Coproduct<CH, CTail>
+#[cfg(feature = "alloc")]
+impl<Head, Tail> CoproductAny for Coproduct<Head, Tail>
 where
-    P: Func<CH>,
-    CTail: CoproductMappable<&'a Poly<P>>,
+    Head: Any,
+    Tail: CoproductAny,
 {
-    type Output =
-        Coproduct<<P as Func<CH>>::Output, <CTail as CoproductMappable<&'a Poly<P>>>::Output>;
+    #[inline(always)]
+    fn try_from_any(boxed: Box<dyn Any>) -> Result<Self, Box<dyn Any>> {
+        match boxed.downcast::<Head>() {
+            Ok(head) => Ok(Coproduct::Inl(*head)),
+            Err(boxed) => Tail::try_from_any(boxed).map(Coproduct::Inr),
+        }
+    }
 
-    #[inline]
-    fn map(self, poly: &'a Poly<P>) -> Self::Output {
+    #[inline(always)]
+    fn into_any(self) -> Box<dyn Any> {
         match self {
-            Coproduct::Inl(l) => Coproduct::Inl(P::call(l)),
-            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(poly)),
+            Coproduct::Inl(head) => Box::new(head),
+            Coproduct::Inr(tail) => tail.into_any(),
         }
     }
 }
 
-/// Implementation for mapping a Coproduct using a `&mut poly_fn!`.
-impl<'a, P, CH, CTail> CoproductMappable<&'a mut Poly<P>> for Coproduct<CH, CTail>
-where
-    P: Func<CH>,
-    CTail: CoproductMappable<&'a mut Poly<P>>,
-{
-    type Output =
-        Coproduct<<P as Func<CH>>::Output, <CTail as CoproductMappable<&'a mut Poly<P>>>::Output>;
+/// Trait for iterating over whichever variant of a coproduct of iterators is present.
+///
+/// `CNil` implements this for every `Item`, which is what allows a coproduct of
+/// iterators to implement [`Iterator`] even though `CNil` itself has no item type.
+///
+/// This trait is part of the implementation of [`Iterator`] for [`Coproduct`].
+pub trait CoproductIterator<Item> {
+    /// Advance the present variant.
+    fn next_variant(&mut self) -> Option<Item>;
 
-    #[inline]
-    fn map(self, poly: &'a mut Poly<P>) -> Self::Output {
-        match self {
-            Coproduct::Inl(l) => Coproduct::Inl(P::call(l)),
-            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(poly)),
-        }
+    /// Returns the size hint of the present variant.
+    fn variant_size_hint(&self) -> (usize, Option<usize>);
+}
+
+impl<Item> CoproductIterator<Item> for CNil {
+    fn next_variant(&mut self) -> Option<Item> {
+        match *self {}
+    }
+
+    fn variant_size_hint(&self) -> (usize, Option<usize>) {
+        match *self {}
     }
 }
 
-/// Implementation for mapping a Coproduct using a single function that can
-/// handle all variants.
-impl<F, R, CH, CTail> CoproductMappable<F> for Coproduct<CH, CTail>
+impl<Head, Tail> CoproductIterator<Head::Item> for Coproduct<Head, Tail>
 where
-    F: FnMut(CH) -> R,
-    CTail: CoproductMappable<F>,
+    Head: Iterator,
+    Tail: CoproductIterator<Head::Item>,
 {
-    type Output = Coproduct<R, <CTail as CoproductMappable<F>>::Output>;
-
-    #[inline]
-    fn map(self, mut f: F) -> Self::Output {
-        match self {
-            Coproduct::Inl(l) => Coproduct::Inl(f(l)),
-            Coproduct::Inr(rest) => Coproduct::Inr(rest.map(f)),
+    #[inline(always)]
+    fn next_variant(&mut self) -> Option<Head::Item> {
+        match *self {
+            Coproduct::Inl(ref mut head) => head.next(),
+            Coproduct::Inr(ref mut tail) => tail.next_variant(),
         }
     }
-}
-
-/// Base case map impl.
-impl<F> CoproductMappable<F> for CNil {
-    type Output = CNil;
 
     #[inline(always)]
-    fn map(self, _: F) -> Self::Output {
-        match self {}
+    fn variant_size_hint(&self) -> (usize, Option<usize>) {
+        match *self {
+            Coproduct::Inl(ref head) => head.size_hint(),
+            Coproduct::Inr(ref tail) => tail.variant_size_hint(),
+        }
     }
 }
 
-impl<'a, CH: 'a, CTail> ToRef<'a> for Coproduct<CH, CTail>
+/// A coproduct of iterators with the same `Item` is itself an iterator, which
+/// dispatches to whichever variant is present.
+///
+/// This makes it possible to return one of several iterator types from a function
+/// without boxing.
+///
+/// Unlike `either::Either`, coproducts do not forward `std::io::Read` and
+/// `std::io::Write`: those traits are only in `std`, and frunk is `no_std` with no
+/// `std`-only code paths (the `std` feature is only an alias for `alloc`). Match on
+/// the coproduct, or fold it, to read from or write to the present variant.
+///
+/// The inherent methods of `Coproduct` named `map`, `fold`, `flat_map`, `flatten` and
+/// `take` shadow the `Iterator` adaptors with the same names, so `co.map(f)` maps the
+/// present variant rather than the items. Call those adaptors through the trait instead,
+/// e.g. `Iterator::map(co, f)`.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// use frunk_core::Coprod;
+///
+/// type Numbers = Coprod!(
+///     std::ops::Range<u32>,
+///     std::iter::Once<u32>,
+///     std::vec::IntoIter<u32>,
+/// );
+///
+/// fn numbers(n: u32) -> Numbers {
+///     match n {
+///         0 => Numbers::inject(std::iter::once(0)),
+///         1 => Numbers::inject(0..3),
+///         _ => Numbers::inject(vec![n, n].into_iter()),
+///     }
+/// }
+///
+/// assert_eq!(numbers(0).collect::<Vec<_>>(), vec![0]);
+/// assert_eq!(numbers(1).rev().collect::<Vec<_>>(), vec![2, 1, 0]);
+/// assert_eq!(numbers(7).len(), 2);
+///
+/// // `numbers(1).map(..)` would be `Coproduct::map`
+/// let doubled: Vec<u32> = Iterator::map(numbers(1), |n| n * 2).collect();
+/// assert_eq!(doubled, vec![0, 2, 4]);
+/// # }
+/// ```
+impl<Head, Tail> Iterator for Coproduct<Head, Tail>
 where
-    CTail: ToRef<'a>,
+    Head: Iterator,
+    Tail: CoproductIterator<Head::Item>,
 {
-    type Output = Coproduct<&'a CH, <CTail as ToRef<'a>>::Output>;
+    type Item = Head::Item;
 
     #[inline(always)]
-    fn to_ref(&'a self) -> Self::Output {
-        match *self {
-            Coproduct::Inl(ref r) => Coproduct::Inl(r),
-            Coproduct::Inr(ref rest) => Coproduct::Inr(rest.to_ref()),
-        }
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_variant()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.variant_size_hint()
     }
 }
 
-impl<'a> ToRef<'a> for CNil {
-    type Output = CNil;
+/// Trait for iterating from the back of whichever variant of a coproduct of
+/// iterators is present.
+///
+/// This trait is part of the implementation of [`DoubleEndedIterator`] for [`Coproduct`].
+pub trait CoproductDoubleEndedIterator<Item>: CoproductIterator<Item> {
+    /// Advance the present variant from the back.
+    fn next_back_variant(&mut self) -> Option<Item>;
+}
 
-    fn to_ref(&'a self) -> CNil {
+impl<Item> CoproductDoubleEndedIterator<Item> for CNil {
+    fn next_back_variant(&mut self) -> Option<Item> {
         match *self {}
     }
 }
 
-impl<'a, CH: 'a, CTail> ToMut<'a> for Coproduct<CH, CTail>
+impl<Head, Tail> CoproductDoubleEndedIterator<Head::Item> for Coproduct<Head, Tail>
 where
-    CTail: ToMut<'a>,
+    Head: DoubleEndedIterator,
+    Tail: CoproductDoubleEndedIterator<Head::Item>,
 {
-    type Output = Coproduct<&'a mut CH, <CTail as ToMut<'a>>::Output>;
-
     #[inline(always)]
-    fn to_mut(&'a mut self) -> Self::Output {
+    fn next_back_variant(&mut self) -> Option<Head::Item> {
         match *self {
-            Coproduct::Inl(ref mut r) => Coproduct::Inl(r),
-            Coproduct::Inr(ref mut rest) => Coproduct::Inr(rest.to_mut()),
+            Coproduct::Inl(ref mut head) => head.next_back(),
+            Coproduct::Inr(ref mut tail) => tail.next_back_variant(),
         }
     }
 }
 
-impl<'a> ToMut<'a> for CNil {
-    type Output = CNil;
-
-    fn to_mut(&'a mut self) -> CNil {
-        match *self {}
+impl<Head, Tail> DoubleEndedIterator for Coproduct<Head, Tail>
+where
+    Head: DoubleEndedIterator,
+    Tail: CoproductDoubleEndedIterator<Head::Item>,
+{
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next_back_variant()
     }
 }
 
-/// Trait for extracting a value from a coproduct in an exhaustive way.
+/// Marker trait for coproducts whose variants are all [`ExactSizeIterator`]s.
 ///
-/// This trait is part of the implementation of the inherent method
-/// [`Coproduct::uninject`]. Please see that method for more information.
-///
-/// You only need to import this trait when working with generic
-/// Coproducts of unknown type. If you have a Coproduct of known type,
-/// then `co.uninject()` should "just work" even without the trait.
-///
-/// [`Coproduct::uninject`]: enum.Coproduct.html#method.uninject
-pub trait CoprodUninjector<T, Idx>: CoprodInjector<T, Idx> {
-    type Remainder;
-
-    /// Attempt to extract a value from a coproduct (or get the remaining possibilities).
-    ///
-    /// Please see the [inherent method] for more information.
-    ///
-    /// The only difference between that inherent method and this
-    /// trait method is the location of the type parameters.
-    /// (here, they are on the trait rather than the method)
-    ///
-    /// [inherent method]: enum.Coproduct.html#method.uninject
-    fn uninject(self) -> Result<T, Self::Remainder>;
-}
+/// This trait is part of the implementation of [`ExactSizeIterator`] for [`Coproduct`].
+pub trait CoproductExactSizeIterator<Item>: CoproductIterator<Item> {}
 
-impl<Hd, Tl> CoprodUninjector<Hd, Here> for Coproduct<Hd, Tl> {
-    type Remainder = Tl;
+impl<Item> CoproductExactSizeIterator<Item> for CNil {}
 
-    fn uninject(self) -> Result<Hd, Tl> {
-        match self {
-            Coproduct::Inl(h) => Ok(h),
-            Coproduct::Inr(t) => Err(t),
-        }
-    }
+impl<Head, Tail> CoproductExactSizeIterator<Head::Item> for Coproduct<Head, Tail>
+where
+    Head: ExactSizeIterator,
+    Tail: CoproductExactSizeIterator<Head::Item>,
+{
 }
 
-impl<Hd, Tl, T, N> CoprodUninjector<T, There<N>> for Coproduct<Hd, Tl>
+impl<Head, Tail> ExactSizeIterator for Coproduct<Head, Tail>
 where
-    Tl: CoprodUninjector<T, N>,
+    Head: ExactSizeIterator,
+    Tail: CoproductExactSizeIterator<Head::Item>,
 {
-    type Remainder = Coproduct<Hd, Tl::Remainder>;
-
-    fn uninject(self) -> Result<T, Self::Remainder> {
-        match self {
-            Coproduct::Inl(h) => Err(Coproduct::Inl(h)),
-            Coproduct::Inr(t) => t.uninject().map_err(Coproduct::Inr),
-        }
-    }
 }
 
-/// Trait for extracting a subset of the possible types in a coproduct.
-///
-/// This trait is part of the implementation of the inherent method
-/// [`Coproduct::subset`]. Please see that method for more information.
-///
-/// You only need to import this trait when working with generic
-/// Coproducts of unknown type. If you have a Coproduct of known type,
-/// then `co.subset()` should "just work" even without the trait.
+/// Marker trait for coproducts whose variants are all [`FusedIterator`]s.
 ///
-/// [`Coproduct::subset`]: enum.Coproduct.html#method.subset
-pub trait CoproductSubsetter<Targets, Indices>: Sized {
-    type Remainder;
+/// This trait is part of the implementation of [`FusedIterator`] for [`Coproduct`].
+pub trait CoproductFusedIterator<Item>: CoproductIterator<Item> {}
 
-    /// Extract a subset of the possible types in a coproduct (or get the remaining possibilities)
-    ///
-    /// Please see the [inherent method] for more information.
-    ///
-    /// The only difference between that inherent method and this
-    /// trait method is the location of the type parameters.
-    /// (here, they are on the trait rather than the method)
-    ///
-    /// [inherent method]: enum.Coproduct.html#method.subset
-    fn subset(self) -> Result<Targets, Self::Remainder>;
-}
+impl<Item> CoproductFusedIterator<Item> for CNil {}
 
-impl<Choices, THead, TTail, NHead, NTail, Rem>
-    CoproductSubsetter<Coproduct<THead, TTail>, HCons<NHead, NTail>> for Choices
+impl<Head, Tail> CoproductFusedIterator<Head::Item> for Coproduct<Head, Tail>
 where
-    Self: CoprodUninjector<THead, NHead, Remainder = Rem>,
-    Rem: CoproductSubsetter<TTail, NTail>,
+    Head: FusedIterator,
+    Tail: CoproductFusedIterator<Head::Item>,
 {
-    type Remainder = <Rem as CoproductSubsetter<TTail, NTail>>::Remainder;
-
-    /// Attempt to extract a value from a subset of the types.
-    fn subset(self) -> Result<Coproduct<THead, TTail>, Self::Remainder> {
-        match self.uninject() {
-            Ok(good) => Ok(Coproduct::Inl(good)),
-            Err(bads) => match bads.subset() {
-                Ok(goods) => Ok(Coproduct::Inr(goods)),
-                Err(bads) => Err(bads),
-            },
-        }
-    }
 }
 
-impl<Choices> CoproductSubsetter<CNil, HNil> for Choices {
-    type Remainder = Self;
-
-    #[inline(always)]
-    fn subset(self) -> Result<CNil, Self::Remainder> {
-        Err(self)
-    }
+impl<Head, Tail> FusedIterator for Coproduct<Head, Tail>
+where
+    Head: FusedIterator,
+    Tail: CoproductFusedIterator<Head::Item>,
+{
 }
 
-/// Trait for converting a coproduct into another that can hold its variants.
-///
-/// This trait is part of the implementation of the inherent method
-/// [`Coproduct::embed`]. Please see that method for more information.
+/// Trait for polling whichever variant of a coproduct of futures is present.
 ///
-/// You only need to import this trait when working with generic
-/// Coproducts of unknown type. If you have a Coproduct of known type,
-/// then `co.embed()` should "just work" even without the trait.
+/// `CNil` implements this for every `Output`, which is what allows a coproduct of
+/// futures to implement [`Future`] even though `CNil` itself has no output type.
 ///
-/// [`Coproduct::embed`]: enum.Coproduct.html#method.embed
-pub trait CoproductEmbedder<Out, Indices> {
-    /// Convert a coproduct into another that can hold its variants.
-    ///
-    /// Please see the [inherent method] for more information.
-    ///
-    /// The only difference between that inherent method and this
-    /// trait method is the location of the type parameters.
-    /// (here, they are on the trait rather than the method)
-    ///
-    /// [inherent method]: enum.Coproduct.html#method.embed
-    fn embed(self) -> Out;
+/// This trait is part of the implementation of [`Future`] for [`Coproduct`].
+pub trait CoproductFuture<Output> {
+    /// Poll the present variant.
+    fn poll_variant(&mut self, cx: &mut Context<'_>) -> Poll<Output>;
 }
 
-impl CoproductEmbedder<CNil, HNil> for CNil {
-    fn embed(self) -> CNil {
-        match self {
-        // impossible!
-    }
+impl<Output> CoproductFuture<Output> for CNil {
+    fn poll_variant(&mut self, _: &mut Context<'_>) -> Poll<Output> {
+        match *self {}
     }
 }
 
-impl<Head, Tail> CoproductEmbedder<Coproduct<Head, Tail>, HNil> for CNil
+impl<Head, Tail> CoproductFuture<Head::Output> for Coproduct<Head, Tail>
 where
-    CNil: CoproductEmbedder<Tail, HNil>,
+    Head: Future + Unpin,
+    Tail: CoproductFuture<Head::Output>,
 {
-    fn embed(self) -> Coproduct<Head, Tail> {
-        match self {
-        // impossible!
-    }
+    #[inline(always)]
+    fn poll_variant(&mut self, cx: &mut Context<'_>) -> Poll<Head::Output> {
+        match *self {
+            Coproduct::Inl(ref mut head) => Pin::new(head).poll(cx),
+            Coproduct::Inr(ref mut tail) => tail.poll_variant(cx),
+        }
     }
 }
 
-impl<Head, Tail, Out, NHead, NTail> CoproductEmbedder<Out, HCons<NHead, NTail>>
-    for Coproduct<Head, Tail>
+/// A coproduct of futures with the same `Output` is itself a future, which
+/// dispatches to whichever variant is present.
+///
+/// Since frunk does not use `unsafe` to project pins through coproducts, every
+/// variant must be [`Unpin`]. Futures that are not can be pinned first, e.g. with
+/// `Box::pin`.
+impl<Head, Tail> Future for Coproduct<Head, Tail>
 where
-    Out: CoprodInjector<Head, NHead>,
-    Tail: CoproductEmbedder<Out, NTail>,
+    Head: Future + Unpin,
+    Tail: CoproductFuture<Head::Output> + Unpin,
 {
-    fn embed(self) -> Out {
-        match self {
-            Coproduct::Inl(this) => Out::inject(this),
-            Coproduct::Inr(those) => those.embed(),
-        }
+    type Output = Head::Output;
+
+    #[inline(always)]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.get_mut().poll_variant(cx)
     }
 }
 
@@ -1247,8 +2860,11 @@ mod tests {
     use super::Coproduct::*;
     use super::*;
 
+    use std::boxed::Box;
     use std::format;
     use std::string::{String, ToString};
+    use std::vec;
+    use std::vec::Vec;
 
     #[test]
     fn test_coproduct_inject() {
@@ -1580,4 +3196,308 @@ mod tests {
         assert_eq!(co, StrStr::Inl(String::from("hi!!")));
         assert_eq!(captured, String::from("hi!hi!!"));
     }
+
+    #[test]
+    fn test_coproduct_extend() {
+        type I32Bool = Coprod!(i32, bool);
+        type StrF32 = Coprod!(&'static str, f32);
+        type I32BoolStrF32 = Coprod!(i32, bool, &'static str, f32);
+
+        let right: I32BoolStrF32 = I32Bool::inject(3).extend_right::<StrF32>();
+        assert_eq!(right, I32BoolStrF32::inject(3));
+
+        let left: I32BoolStrF32 = StrF32::inject("hi").extend_left::<I32Bool>();
+        assert_eq!(left, I32BoolStrF32::inject("hi"));
+
+        let unchanged: I32Bool = I32Bool::inject(true).extend_right::<CNil>();
+        assert_eq!(unchanged, I32Bool::inject(true));
+        let unchanged: I32Bool = I32Bool::inject(true).extend_left::<CNil>();
+        assert_eq!(unchanged, I32Bool::inject(true));
+    }
+
+    #[test]
+    fn test_coproduct_flatten() {
+        type Nested = Coprod!(
+            Coprod!(i32, bool),
+            CNil,
+            Coprod!(&'static str),
+            Coprod!(f32)
+        );
+        type Flat = Coprod!(i32, bool, &'static str, f32);
+
+        let co = Nested::inject(<Coprod!(i32, bool)>::inject(true));
+        assert_eq!(co.flatten(), Flat::inject(true));
+
+        let co = Nested::inject(<Coprod!(&'static str)>::inject("hi"));
+        assert_eq!(co.flatten(), Flat::inject("hi"));
+
+        let co = Nested::inject(<Coprod!(f32)>::inject(1f32));
+        assert_eq!(co.flatten(), Flat::inject(1f32));
+    }
+
+    #[test]
+    fn test_coproduct_variants() {
+        type I32StrBool = Coprod!(i32, &'static str, bool);
+
+        assert_eq!(<Coprod!() as CoproductVariants>::LEN, 0);
+        assert_eq!(<I32StrBool as CoproductVariants>::LEN, 3);
+
+        let co1 = I32StrBool::inject(3);
+        let co2 = I32StrBool::inject("hello");
+        let co3 = I32StrBool::inject(false);
+
+        assert_eq!(co1.variant_index(), 0);
+        assert_eq!(co2.variant_index(), 1);
+        assert_eq!(co3.variant_index(), 2);
+
+        assert_eq!(co1.variant_type_name(), "i32");
+        assert_eq!(co2.variant_type_name(), "&str");
+        assert_eq!(co3.variant_type_name(), "bool");
+    }
+
+    #[test]
+    fn test_result_inject_err_and_embed_err() {
+        type I32Bool = Coprod!(i32, bool);
+        type BoolStrI32 = Coprod!(bool, &'static str, i32);
+
+        let ok: Result<u8, i32> = Ok(1);
+        assert_eq!(ok.inject_err::<I32Bool, _>(), Ok(1));
+
+        let err: Result<u8, bool> = Err(true);
+        assert_eq!(err.inject_err(), Err(I32Bool::inject(true)));
+
+        let err: Result<u8, I32Bool> = Err(I32Bool::inject(3));
+        assert_eq!(err.embed_err(), Err(BoolStrI32::inject(3)));
+
+        fn lifted() -> Result<u8, BoolStrI32> {
+            let err: Result<u8, &'static str> = Err("nope");
+            err.inject_err()
+        }
+        assert_eq!(lifted(), Err(BoolStrI32::inject("nope")));
+    }
+
+    #[test]
+    fn test_coproduct_at() {
+        type I32I32Str = Coprod!(i32, i32, &'static str);
+
+        let first = I32I32Str::inject_at::<Here>(1);
+        let second = I32I32Str::inject_at::<There<Here>>(2);
+        assert_eq!(first, Inl(1));
+        assert_eq!(second, Inr(Inl(2)));
+
+        assert_eq!(first.get_at::<Here>(), Some(&1));
+        assert_eq!(first.get_at::<There<Here>>(), None);
+        assert_eq!(second.get_at::<There<Here>>(), Some(&2));
+        assert_eq!(second.take_at::<Here>(), None);
+        assert_eq!(second.take_at::<There<Here>>(), Some(2));
+
+        let third = I32I32Str::inject_at::<There<There<Here>>>("three");
+        assert_eq!(third, I32I32Str::inject("three"));
+        assert_eq!(third.take_at::<There<There<Here>>>(), Some("three"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_coproduct_any() {
+        type I32StrUnit = Coprod!(i32, String, ());
+
+        for co in [
+            I32StrUnit::inject(1),
+            I32StrUnit::inject(String::from("s")),
+            I32StrUnit::inject(()),
+        ] {
+            let round_tripped = I32StrUnit::try_from_any(co.clone().into_any());
+            assert_eq!(round_tripped.ok(), Some(co));
+        }
+
+        let rejected = I32StrUnit::try_from_any(Box::new(1u8)).unwrap_err();
+        assert_eq!(rejected.downcast_ref::<u8>(), Some(&1));
+    }
+
+    #[test]
+    fn test_distribute() {
+        type Choices = HList![Coprod!(i32, bool), Coprod!(char), Coprod!(u8, &'static str)];
+        type Cases = Coprod!(
+            HList![i32, char, u8],
+            HList![i32, char, &'static str],
+            HList![bool, char, u8],
+            HList![bool, char, &'static str],
+        );
+
+        let choices: Choices = hlist![
+            Coproduct::inject(true),
+            Coproduct::inject('c'),
+            Coproduct::inject(3u8),
+        ];
+        let cases: Cases = choices.distribute();
+        assert_eq!(cases, Cases::inject(hlist![true, 'c', 3u8]));
+        assert_eq!(cases.variant_index(), 2);
+        assert_eq!(Choices::undistribute(cases), choices);
+
+        let choices: Choices = hlist![
+            Coproduct::inject(1),
+            Coproduct::inject('c'),
+            Coproduct::inject("s"),
+        ];
+        let cases: Cases = choices.distribute();
+        assert_eq!(cases, Cases::inject(hlist![1, 'c', "s"]));
+        assert_eq!(Choices::undistribute(cases), choices);
+
+        assert_eq!(HNil.distribute(), Coproduct::Inl(HNil));
+    }
+
+    #[test]
+    fn test_coproduct_fold_ref_and_mut() {
+        type I32StrBool = Coprod!(i32, &'static str, bool);
+
+        let mut co = I32StrBool::inject(3);
+        let described = co.fold_ref(hlist![
+            |&i| format!("int {}", i),
+            |&s| format!("str {}", s),
+            |&b| format!("bool {}", b),
+        ]);
+        assert_eq!(described, "int 3");
+
+        let bump = poly_fn![
+            |i: &mut i32| -> () { *i += 1 },
+            ['a] |_s: &'a mut &'static str| -> () {},
+            |b: &mut bool| -> () { *b = !*b },
+        ];
+        co.fold_mut(bump);
+        co.fold_mut(bump);
+        assert_eq!(co, I32StrBool::inject(5));
+    }
+
+    #[test]
+    fn test_coproduct_flat_map() {
+        type I32Bool = Coprod!(i32, bool);
+
+        let mapper = hlist![
+            |n: i32| <Coprod!(i32, String)>::inject(n.to_string()),
+            |b: bool| <Coprod!(bool)>::inject(!b),
+        ];
+        let co: Coprod!(i32, String, bool) = I32Bool::inject(4).flat_map(&mapper);
+        assert_eq!(co, Coproduct::inject(String::from("4")));
+        let co: Coprod!(i32, String, bool) = I32Bool::inject(true).flat_map(&mapper);
+        assert_eq!(co, Coproduct::inject(false));
+    }
+
+    #[test]
+    fn test_coproduct_try_map() {
+        type I32Bool = Coprod!(i32, bool);
+        type U8Bool = Coprod!(u8, bool);
+
+        let mapper = hlist![
+            |n: i32| u8::try_from(n).map_err(|_| "out of range"),
+            Ok::<_, &str>
+        ];
+        assert_eq!(I32Bool::inject(7).try_map(&mapper), Ok(U8Bool::inject(7u8)));
+        assert_eq!(I32Bool::inject(-7).try_map(&mapper), Err("out of range"));
+        assert_eq!(
+            I32Bool::inject(true).try_map(&mapper),
+            Ok(U8Bool::inject(true))
+        );
+    }
+
+    #[test]
+    fn test_coproduct_unify() {
+        type Strs = Coprod!(String, String, String);
+
+        let co = Strs::Inr(Inr(Inl(String::from("last"))));
+        assert_eq!(co.to_ref().unify(), "last");
+        assert_eq!(co.into_inner(), "last");
+
+        let co = <Coprod!(i32, f32)>::inject(2f32);
+        let unified: f64 = co.map(hlist![f64::from, f64::from]).unify();
+        assert_eq!(unified, 2.0);
+    }
+
+    #[test]
+    fn test_coproduct_as_dyn() {
+        use core::fmt::{Debug, Display};
+
+        let s = String::from("borrowed");
+        let mut co = <Coprod!(i32, &str, bool)>::inject(s.as_str());
+        assert_eq!(format!("{}", co.as_dyn::<dyn Display>()), "borrowed");
+        assert_eq!(
+            format!("{:?}", co.as_dyn_mut::<dyn Debug>()),
+            "\"borrowed\""
+        );
+
+        let mut co = <Coprod!(i32, String)>::inject(3);
+        let any: &mut dyn Any = co.as_dyn_mut();
+        *any.downcast_mut::<i32>().unwrap() += 1;
+        assert_eq!(co.as_dyn::<dyn Any>().downcast_ref::<String>(), None);
+        assert_eq!(co.as_dyn::<dyn Any>().downcast_ref::<i32>(), Some(&4));
+    }
+
+    #[test]
+    fn test_coproduct_iterator() {
+        type Iters = Coprod!(
+            core::ops::Range<u8>,
+            core::iter::Once<u8>,
+            core::iter::Rev<core::ops::Range<u8>>
+        );
+
+        let co = Iters::inject(0..3);
+        assert_eq!(co.len(), 3);
+        assert_eq!(co.collect::<Vec<_>>(), vec![0, 1, 2]);
+
+        let co = Iters::inject(core::iter::once(7));
+        assert_eq!(co.size_hint(), (1, Some(1)));
+        assert_eq!(co.rev().collect::<Vec<_>>(), vec![7]);
+
+        let mut co = Iters::inject((0..3).rev());
+        assert_eq!(co.next(), Some(2));
+        assert_eq!(co.next_back(), Some(0));
+        assert_eq!(co.next(), Some(1));
+        assert_eq!(co.next(), None);
+        assert_eq!(co.next(), None);
+    }
+
+    #[test]
+    fn test_coproduct_iterator_shadowed_adaptors() {
+        type Iters = Coprod!(core::ops::Range<u8>, core::iter::Once<u8>);
+
+        let mapped = Iterator::map(Iters::inject(0..3), |n| n * 10);
+        assert_eq!(mapped.collect::<Vec<_>>(), vec![0, 10, 20]);
+        assert_eq!(Iterator::fold(Iters::inject(0..4), 0, |acc, n| acc + n), 6);
+        let flat_mapped = Iterator::flat_map(Iters::inject(core::iter::once(2)), |n| 0..n);
+        assert_eq!(flat_mapped.collect::<Vec<_>>(), vec![0, 1]);
+        let taken = Iterator::take(Iters::inject(0..10), 2);
+        assert_eq!(taken.collect::<Vec<_>>(), vec![0, 1]);
+
+        type Nested = Coprod!(
+            core::iter::Once<core::ops::Range<u8>>,
+            core::option::IntoIter<core::ops::Range<u8>>
+        );
+        let flattened = Iterator::flatten(Nested::inject(core::iter::once(1..3)));
+        assert_eq!(flattened.collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_coproduct_future() {
+        use core::future::{ready, Ready};
+        use core::task::Waker;
+        use std::sync::Arc;
+        use std::task::Wake;
+
+        // `Waker::noop` would do, but it needs Rust 1.85, which frunk does not require
+        struct NoopWaker;
+
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        type Futs = Coprod!(Ready<i32>, Pin<Box<dyn Future<Output = i32>>>);
+
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+
+        let mut co = Futs::inject(ready(1));
+        assert_eq!(Pin::new(&mut co).poll(&mut cx), Poll::Ready(1));
+
+        let mut co = Futs::inject(Box::pin(async { 2 }) as Pin<Box<dyn Future<Output = i32>>>);
+        assert_eq!(Pin::new(&mut co).poll(&mut cx), Poll::Ready(2));
+    }
 }
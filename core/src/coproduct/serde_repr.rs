@@ -0,0 +1,1098 @@
+//! Opt-in serde representations for Coproducts.
+//!
+//! By default, a `Coproduct` serializes as nested `Inl`/`Inr` enum variants, which
+//! faithfully round-trips but is awkward to consume from other languages. The
+//! modules in here provide flatter encodings that can be selected per field with
+//! `#[serde(with = "...")]`, or for a whole value with the wrapper types.
+//!
+//! * [`untagged`]: the value of the active variant on its own, e.g. `3`. On the way
+//!   back in, each variant is tried in order and the first that succeeds wins.
+//!   Deserializing requires the `alloc` feature and a self-describing format.
+//! * [`index_tagged`]: the position of the active variant next to its value,
+//!   e.g. `{"index":0,"value":3}`.
+//! * [`type_name_tagged`]: the [`core::any::type_name`] of the active variant next
+//!   to its value, e.g. `{"type":"i32","value":3}`. Type names are not guaranteed
+//!   to be stable across compiler versions, so prefer `index_tagged` for data
+//!   that outlives a single build.
+//!
+//! # Examples
+//!
+//! ```
+//! # fn main() {
+//! use frunk_core::Coprod;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Reading {
+//!     #[serde(with = "frunk_core::coproduct::serde_repr::untagged")]
+//!     untagged: Coprod!(i32, String),
+//!     #[serde(with = "frunk_core::coproduct::serde_repr::index_tagged")]
+//!     indexed: Coprod!(i32, String),
+//! }
+//!
+//! let reading = Reading {
+//!     untagged: <Coprod!(i32, String)>::inject(String::from("high")),
+//!     indexed: <Coprod!(i32, String)>::inject(3),
+//! };
+//!
+//! let json = serde_json::to_string(&reading).unwrap();
+//! assert_eq!(json, r#"{"untagged":"high","indexed":{"index":0,"value":3}}"#);
+//! assert_eq!(serde_json::from_str::<Reading>(&json).unwrap(), reading);
+//! # }
+//! ```
+
+use super::{CNil, Coproduct, CoproductVariants};
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+/// Trait for serializing the value held by the active variant of a Coproduct.
+///
+/// Implemented for `CNil` and every `Coproduct<H, T>` whose variants are all `Serialize`.
+pub trait SerializeVariant: CoproductVariants {
+    /// Serialize the value of the active variant, without any tag.
+    fn serialize_variant<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+}
+
+impl SerializeVariant for CNil {
+    fn serialize_variant<S>(&self, _: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {}
+    }
+}
+
+impl<Head, Tail> SerializeVariant for Coproduct<Head, Tail>
+where
+    Head: Serialize,
+    Tail: SerializeVariant,
+{
+    fn serialize_variant<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Coproduct::Inl(ref head) => head.serialize(serializer),
+            Coproduct::Inr(ref tail) => tail.serialize_variant(serializer),
+        }
+    }
+}
+
+/// Trait for deserializing a Coproduct one variant at a time.
+///
+/// Implemented for `CNil` and every `Coproduct<H, T>` whose variants are all `Deserialize`.
+pub trait DeserializeVariant<'de>: CoproductVariants + Sized {
+    /// Deserialize the variant at position `index`.
+    fn deserialize_variant<D>(index: usize, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+
+    /// Try to deserialize each variant in order, using a fresh deserializer from
+    /// `deserializers` for each attempt, and return the first success. Each failed
+    /// attempt is passed to `on_error` along with the type name of its variant.
+    fn deserialize_first_variant<F, D, E>(deserializers: &F, on_error: &mut E) -> Option<Self>
+    where
+        F: Fn() -> D,
+        D: Deserializer<'de>,
+        E: FnMut(&'static str, D::Error);
+
+    /// Returns the position of the variant whose [`core::any::type_name`] is `type_name`.
+    fn variant_index_of(type_name: &str) -> Option<usize>;
+}
+
+impl<'de> DeserializeVariant<'de> for CNil {
+    fn deserialize_variant<D>(index: usize, _: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Err(de::Error::custom(format_args!(
+            "no coproduct variant at index {}",
+            index
+        )))
+    }
+
+    fn deserialize_first_variant<F, D, E>(_: &F, _: &mut E) -> Option<Self>
+    where
+        F: Fn() -> D,
+        D: Deserializer<'de>,
+        E: FnMut(&'static str, D::Error),
+    {
+        None
+    }
+
+    fn variant_index_of(_: &str) -> Option<usize> {
+        None
+    }
+}
+
+impl<'de, Head, Tail> DeserializeVariant<'de> for Coproduct<Head, Tail>
+where
+    Head: Deserialize<'de>,
+    Tail: DeserializeVariant<'de>,
+{
+    fn deserialize_variant<D>(index: usize, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match index {
+            0 => Head::deserialize(deserializer).map(Coproduct::Inl),
+            _ => Tail::deserialize_variant(index - 1, deserializer).map(Coproduct::Inr),
+        }
+    }
+
+    fn deserialize_first_variant<F, D, E>(deserializers: &F, on_error: &mut E) -> Option<Self>
+    where
+        F: Fn() -> D,
+        D: Deserializer<'de>,
+        E: FnMut(&'static str, D::Error),
+    {
+        match Head::deserialize(deserializers()) {
+            Ok(head) => Some(Coproduct::Inl(head)),
+            Err(error) => {
+                on_error(core::any::type_name::<Head>(), error);
+                Tail::deserialize_first_variant(deserializers, on_error).map(Coproduct::Inr)
+            }
+        }
+    }
+
+    fn variant_index_of(type_name: &str) -> Option<usize> {
+        if core::any::type_name::<Head>() == type_name {
+            Some(0)
+        } else {
+            Tail::variant_index_of(type_name).map(|index| index + 1)
+        }
+    }
+}
+
+/// Serializes the active variant of a borrowed Coproduct without a tag.
+struct Variant<'a, C>(&'a C);
+
+impl<C> Serialize for Variant<'_, C>
+where
+    C: SerializeVariant,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize_variant(serializer)
+    }
+}
+
+/// Deserializes the variant of a Coproduct at a known position.
+struct VariantSeed<C> {
+    index: usize,
+    _marker: PhantomData<C>,
+}
+
+impl<'de, C> DeserializeSeed<'de> for VariantSeed<C>
+where
+    C: DeserializeVariant<'de>,
+{
+    type Value = C;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        C::deserialize_variant(self.index, deserializer)
+    }
+}
+
+/// Serializes the value of the active variant on its own, and deserializes by
+/// trying each variant in order.
+///
+/// Use with `#[serde(with = "frunk_core::coproduct::serde_repr::untagged")]`, or wrap
+/// the Coproduct in [`Untagged`].
+pub mod untagged {
+    use super::*;
+
+    pub fn serialize<C, S>(value: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: SerializeVariant,
+        S: Serializer,
+    {
+        value.serialize_variant(serializer)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: DeserializeVariant<'de>,
+        D: Deserializer<'de>,
+    {
+        use self::content::{Content, ContentDeserializer};
+        use alloc::string::String;
+        use core::fmt::Write;
+
+        let content = Content::deserialize(deserializer)?;
+        let mut errors = String::new();
+        C::deserialize_first_variant(
+            &|| ContentDeserializer::<de::value::Error>::new(content.clone()),
+            &mut |type_name, error| {
+                let separator = if errors.is_empty() { "" } else { "; " };
+                let _ = write!(errors, "{}{}: {}", separator, type_name, error);
+            },
+        )
+        .ok_or_else(|| {
+            de::Error::custom(format_args!(
+                "data did not match any variant of the coproduct ({})",
+                errors
+            ))
+        })
+    }
+}
+
+/// Serializes the position of the active variant alongside its value, as a struct
+/// with an `index` and a `value` field.
+///
+/// Use with `#[serde(with = "frunk_core::coproduct::serde_repr::index_tagged")]`, or wrap
+/// the Coproduct in [`IndexTagged`].
+pub mod index_tagged {
+    use super::*;
+
+    const FIELDS: &[&str] = &["index", "value"];
+
+    pub fn serialize<C, S>(value: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: SerializeVariant,
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Coproduct", 2)?;
+        state.serialize_field("index", &(value.variant_index() as u64))?;
+        state.serialize_field("value", &Variant(value))?;
+        state.end()
+    }
+
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: DeserializeVariant<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("Coproduct", FIELDS, TaggedVisitor::<C, IndexTag>::new())
+    }
+}
+
+/// Serializes the type name of the active variant alongside its value, as a struct
+/// with a `type` and a `value` field.
+///
+/// Use with `#[serde(with = "frunk_core::coproduct::serde_repr::type_name_tagged")]`, or
+/// wrap the Coproduct in [`TypeNameTagged`].
+pub mod type_name_tagged {
+    use super::*;
+
+    const FIELDS: &[&str] = &["type", "value"];
+
+    pub fn serialize<C, S>(value: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: SerializeVariant,
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Coproduct", 2)?;
+        state.serialize_field("type", value.variant_type_name())?;
+        state.serialize_field("value", &Variant(value))?;
+        state.end()
+    }
+
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: DeserializeVariant<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            "Coproduct",
+            FIELDS,
+            TaggedVisitor::<C, TypeNameTag<C>>::new(),
+        )
+    }
+}
+
+macro_rules! wrapper {
+    ($(#[$attr:meta])* $name:ident, $module:ident) => {
+        $(#[$attr])*
+        #[derive(PartialEq, Debug, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+        pub struct $name<C>(pub C);
+
+        impl<C> Serialize for $name<C>
+        where
+            C: SerializeVariant,
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                $module::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de, C> Deserialize<'de> for $name<C>
+        where
+            C: DeserializeVariant<'de>,
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                $module::deserialize(deserializer).map($name)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+wrapper! {
+    /// Wrapper that (de)serializes a Coproduct using the [`untagged`] representation.
+    Untagged, untagged
+}
+
+wrapper! {
+    /// Wrapper that (de)serializes a Coproduct using the [`index_tagged`] representation.
+    IndexTagged, index_tagged
+}
+
+wrapper! {
+    /// Wrapper that (de)serializes a Coproduct using the [`type_name_tagged`] representation.
+    TypeNameTagged, type_name_tagged
+}
+
+/// Reads the tag of a tagged representation, resolving it to a variant index.
+trait Tag<'de>: DeserializeSeed<'de, Value = usize> {
+    const FIELD: &'static str;
+
+    fn new() -> Self;
+}
+
+struct IndexTag;
+
+impl<'de> DeserializeSeed<'de> for IndexTag {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<usize, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        usize::deserialize(deserializer)
+    }
+}
+
+impl Tag<'_> for IndexTag {
+    const FIELD: &'static str = "index";
+
+    fn new() -> Self {
+        IndexTag
+    }
+}
+
+struct TypeNameTag<C>(PhantomData<C>);
+
+impl<'de, C> DeserializeSeed<'de> for TypeNameTag<C>
+where
+    C: DeserializeVariant<'de>,
+{
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<usize, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de, C> Visitor<'de> for TypeNameTag<C>
+where
+    C: DeserializeVariant<'de>,
+{
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("the type name of a coproduct variant")
+    }
+
+    fn visit_str<E>(self, type_name: &str) -> Result<usize, E>
+    where
+        E: de::Error,
+    {
+        C::variant_index_of(type_name).ok_or_else(|| {
+            de::Error::custom(format_args!(
+                "unknown coproduct variant type `{}`",
+                type_name
+            ))
+        })
+    }
+}
+
+impl<'de, C> Tag<'de> for TypeNameTag<C>
+where
+    C: DeserializeVariant<'de>,
+{
+    const FIELD: &'static str = "type";
+
+    fn new() -> Self {
+        TypeNameTag(PhantomData)
+    }
+}
+
+enum TaggedField {
+    Tag,
+    Value,
+    Other,
+}
+
+struct TaggedFieldVisitor<T>(PhantomData<T>);
+
+impl<'de, T> DeserializeSeed<'de> for TaggedFieldVisitor<T>
+where
+    T: Tag<'de>,
+{
+    type Value = TaggedField;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<TaggedField, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de, T> Visitor<'de> for TaggedFieldVisitor<T>
+where
+    T: Tag<'de>,
+{
+    type Value = TaggedField;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "`{}` or `value`", T::FIELD)
+    }
+
+    fn visit_u64<E>(self, field: u64) -> Result<TaggedField, E>
+    where
+        E: de::Error,
+    {
+        Ok(match field {
+            0 => TaggedField::Tag,
+            1 => TaggedField::Value,
+            _ => TaggedField::Other,
+        })
+    }
+
+    fn visit_str<E>(self, field: &str) -> Result<TaggedField, E>
+    where
+        E: de::Error,
+    {
+        Ok(if field == T::FIELD {
+            TaggedField::Tag
+        } else if field == "value" {
+            TaggedField::Value
+        } else {
+            TaggedField::Other
+        })
+    }
+
+    fn visit_bytes<E>(self, field: &[u8]) -> Result<TaggedField, E>
+    where
+        E: de::Error,
+    {
+        Ok(if field == T::FIELD.as_bytes() {
+            TaggedField::Tag
+        } else if field == b"value" {
+            TaggedField::Value
+        } else {
+            TaggedField::Other
+        })
+    }
+}
+
+struct TaggedVisitor<C, T>(PhantomData<(C, T)>);
+
+impl<C, T> TaggedVisitor<C, T> {
+    fn new() -> Self {
+        TaggedVisitor(PhantomData)
+    }
+}
+
+impl<'de, C, T> Visitor<'de> for TaggedVisitor<C, T>
+where
+    C: DeserializeVariant<'de>,
+    T: Tag<'de>,
+{
+    type Value = C;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a coproduct with `{}` and `value` fields",
+            T::FIELD
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<C, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let index = seq
+            .next_element_seed(T::new())?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        seq.next_element_seed(VariantSeed {
+            index,
+            _marker: PhantomData,
+        })?
+        .ok_or_else(|| de::Error::invalid_length(1, &self))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<C, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut index = None;
+        let mut value = None;
+        #[cfg(feature = "alloc")]
+        let mut buffered = None;
+        while let Some(field) = map.next_key_seed(TaggedFieldVisitor::<T>(PhantomData))? {
+            match field {
+                TaggedField::Tag if index.is_some() => {
+                    return Err(de::Error::duplicate_field(T::FIELD));
+                }
+                TaggedField::Tag => index = Some(map.next_value_seed(T::new())?),
+                TaggedField::Value => match index {
+                    Some(index) => {
+                        value = Some(map.next_value_seed(VariantSeed {
+                            index,
+                            _marker: PhantomData,
+                        })?)
+                    }
+                    #[cfg(feature = "alloc")]
+                    None => buffered = Some(map.next_value::<content::Content>()?),
+                    #[cfg(not(feature = "alloc"))]
+                    None => {
+                        return Err(de::Error::custom(format_args!(
+                            "`{}` must come before `value`",
+                            T::FIELD
+                        )))
+                    }
+                },
+                TaggedField::Other => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        #[cfg(feature = "alloc")]
+        if let (Some(index), Some(buffered)) = (index, buffered) {
+            value = Some(C::deserialize_variant(
+                index,
+                content::ContentDeserializer::new(buffered),
+            )?);
+        }
+        match value {
+            Some(value) => Ok(value),
+            None if index.is_none() => Err(de::Error::missing_field(T::FIELD)),
+            None => Err(de::Error::missing_field("value")),
+        }
+    }
+}
+
+/// A self-describing buffer for deserialized data, used where the data has to be
+/// read more than once (untagged Coproducts), or before it can be interpreted
+/// (a tagged Coproduct whose value precedes its tag).
+///
+/// This is a trimmed-down copy of the `Content` buffer that serde uses for
+/// `#[serde(untagged)]` and internally tagged enums, in `serde/src/private/de.rs`.
+/// serde only exposes that buffer through its `__private` module, which is not part
+/// of its public API and can change in any release, so it is duplicated here. Unlike
+/// serde's, it always owns its data, and it leaves out the borrowed variants.
+#[cfg(feature = "alloc")]
+mod content {
+    use alloc::boxed::Box;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::de::value::{MapDeserializer, SeqDeserializer};
+    use serde::de::{
+        self, Deserialize, Deserializer, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+        Unexpected, VariantAccess, Visitor,
+    };
+    use serde::forward_to_deserialize_any;
+
+    #[derive(Clone)]
+    pub enum Content {
+        Bool(bool),
+        U64(u64),
+        I64(i64),
+        U128(u128),
+        I128(i128),
+        F64(f64),
+        Char(char),
+        String(String),
+        Bytes(Vec<u8>),
+        None,
+        Some(Box<Content>),
+        Unit,
+        Newtype(Box<Content>),
+        Seq(Vec<Content>),
+        Map(Vec<(Content, Content)>),
+    }
+
+    impl Content {
+        fn unexpected(&self) -> Unexpected<'_> {
+            match *self {
+                Content::Bool(b) => Unexpected::Bool(b),
+                Content::U64(n) => Unexpected::Unsigned(n),
+                Content::I64(n) => Unexpected::Signed(n),
+                Content::U128(_) => Unexpected::Other("u128"),
+                Content::I128(_) => Unexpected::Other("i128"),
+                Content::F64(f) => Unexpected::Float(f),
+                Content::Char(c) => Unexpected::Char(c),
+                Content::String(ref s) => Unexpected::Str(s),
+                Content::Bytes(ref b) => Unexpected::Bytes(b),
+                Content::None | Content::Some(_) => Unexpected::Option,
+                Content::Unit => Unexpected::Unit,
+                Content::Newtype(_) => Unexpected::NewtypeStruct,
+                Content::Seq(_) => Unexpected::Seq,
+                Content::Map(_) => Unexpected::Map,
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Content {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(ContentVisitor)
+        }
+    }
+
+    struct ContentVisitor;
+
+    impl<'de> Visitor<'de> for ContentVisitor {
+        type Value = Content;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("any value")
+        }
+
+        fn visit_bool<E: de::Error>(self, v: bool) -> Result<Content, E> {
+            Ok(Content::Bool(v))
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Content, E> {
+            Ok(Content::I64(v))
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Content, E> {
+            Ok(Content::U64(v))
+        }
+
+        fn visit_u128<E: de::Error>(self, v: u128) -> Result<Content, E> {
+            Ok(Content::U128(v))
+        }
+
+        fn visit_i128<E: de::Error>(self, v: i128) -> Result<Content, E> {
+            Ok(Content::I128(v))
+        }
+
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<Content, E> {
+            Ok(Content::F64(v))
+        }
+
+        fn visit_char<E: de::Error>(self, v: char) -> Result<Content, E> {
+            Ok(Content::Char(v))
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Content, E> {
+            Ok(Content::String(v.into()))
+        }
+
+        fn visit_string<E: de::Error>(self, v: String) -> Result<Content, E> {
+            Ok(Content::String(v))
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Content, E> {
+            Ok(Content::Bytes(v.into()))
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Content, E> {
+            Ok(Content::Bytes(v))
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Content, E> {
+            Ok(Content::None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Content, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Content::deserialize(deserializer).map(|v| Content::Some(Box::new(v)))
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Content, E> {
+            Ok(Content::Unit)
+        }
+
+        fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Content, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Content::deserialize(deserializer).map(|v| Content::Newtype(Box::new(v)))
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Content, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(item) = seq.next_element()? {
+                items.push(item);
+            }
+            Ok(Content::Seq(items))
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Content, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0));
+            while let Some(entry) = map.next_entry()? {
+                entries.push(entry);
+            }
+            Ok(Content::Map(entries))
+        }
+    }
+
+    pub struct ContentDeserializer<E> {
+        content: Content,
+        _marker: PhantomData<E>,
+    }
+
+    impl<E> ContentDeserializer<E> {
+        pub fn new(content: Content) -> Self {
+            ContentDeserializer {
+                content,
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    impl<'de, E> IntoDeserializer<'de, E> for Content
+    where
+        E: de::Error,
+    {
+        type Deserializer = ContentDeserializer<E>;
+
+        fn into_deserializer(self) -> ContentDeserializer<E> {
+            ContentDeserializer::new(self)
+        }
+    }
+
+    impl<'de, E> Deserializer<'de> for ContentDeserializer<E>
+    where
+        E: de::Error,
+    {
+        type Error = E;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, E>
+        where
+            V: Visitor<'de>,
+        {
+            match self.content {
+                Content::Bool(v) => visitor.visit_bool(v),
+                Content::U64(v) => visitor.visit_u64(v),
+                Content::I64(v) => visitor.visit_i64(v),
+                Content::U128(v) => visitor.visit_u128(v),
+                Content::I128(v) => visitor.visit_i128(v),
+                Content::F64(v) => visitor.visit_f64(v),
+                Content::Char(v) => visitor.visit_char(v),
+                Content::String(v) => visitor.visit_string(v),
+                Content::Bytes(v) => visitor.visit_byte_buf(v),
+                Content::None => visitor.visit_none(),
+                Content::Some(v) => visitor.visit_some(ContentDeserializer::new(*v)),
+                Content::Unit => visitor.visit_unit(),
+                Content::Newtype(v) => visitor.visit_newtype_struct(ContentDeserializer::new(*v)),
+                Content::Seq(v) => {
+                    let mut seq = SeqDeserializer::new(v.into_iter());
+                    let value = visitor.visit_seq(&mut seq)?;
+                    seq.end()?;
+                    Ok(value)
+                }
+                Content::Map(v) => {
+                    let mut map = MapDeserializer::new(v.into_iter());
+                    let value = visitor.visit_map(&mut map)?;
+                    map.end()?;
+                    Ok(value)
+                }
+            }
+        }
+
+        fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, E>
+        where
+            V: Visitor<'de>,
+        {
+            match self.content {
+                Content::None | Content::Unit => visitor.visit_none(),
+                Content::Some(v) => visitor.visit_some(ContentDeserializer::new(*v)),
+                _ => visitor.visit_some(self),
+            }
+        }
+
+        fn deserialize_newtype_struct<V>(self, _: &'static str, visitor: V) -> Result<V::Value, E>
+        where
+            V: Visitor<'de>,
+        {
+            match self.content {
+                Content::Newtype(v) => visitor.visit_newtype_struct(ContentDeserializer::new(*v)),
+                _ => visitor.visit_newtype_struct(self),
+            }
+        }
+
+        fn deserialize_enum<V>(
+            self,
+            _: &'static str,
+            _: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, E>
+        where
+            V: Visitor<'de>,
+        {
+            match self.content {
+                Content::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+                Content::Map(entries) if entries.len() == 1 => {
+                    let (variant, value) = entries.into_iter().next().unwrap();
+                    visitor.visit_enum(EnumDeserializer {
+                        variant,
+                        value,
+                        _marker: PhantomData,
+                    })
+                }
+                ref other => Err(de::Error::invalid_type(other.unexpected(), &"an enum")),
+            }
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+            identifier ignored_any
+        }
+    }
+
+    struct EnumDeserializer<E> {
+        variant: Content,
+        value: Content,
+        _marker: PhantomData<E>,
+    }
+
+    impl<'de, E> EnumAccess<'de> for EnumDeserializer<E>
+    where
+        E: de::Error,
+    {
+        type Error = E;
+        type Variant = ContentDeserializer<E>;
+
+        fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), E>
+        where
+            V: de::DeserializeSeed<'de>,
+        {
+            let variant = seed.deserialize(ContentDeserializer::new(self.variant))?;
+            Ok((variant, ContentDeserializer::new(self.value)))
+        }
+    }
+
+    impl<'de, E> VariantAccess<'de> for ContentDeserializer<E>
+    where
+        E: de::Error,
+    {
+        type Error = E;
+
+        fn unit_variant(self) -> Result<(), E> {
+            match self.content {
+                Content::Unit => Ok(()),
+                ref other => Err(de::Error::invalid_type(other.unexpected(), &"unit variant")),
+            }
+        }
+
+        fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, E>
+        where
+            T: de::DeserializeSeed<'de>,
+        {
+            seed.deserialize(self)
+        }
+
+        fn tuple_variant<V>(self, _: usize, visitor: V) -> Result<V::Value, E>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+
+        fn struct_variant<V>(self, _: &'static [&'static str], visitor: V) -> Result<V::Value, E>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_any(visitor)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use std::string::{String, ToString};
+    use std::vec;
+    use std::vec::Vec;
+
+    type I32StrVec = Coprod!(i32, String, Vec<bool>);
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Unit;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum Shape {
+        Dot,
+        Circle(f64),
+        Rect { w: u32, h: u32 },
+    }
+
+    #[test]
+    fn test_untagged_round_trip() {
+        let co = I32StrVec::inject(vec![true, false]);
+        let json = serde_json::to_string(&Untagged(co)).unwrap();
+        assert_eq!(json, "[true,false]");
+
+        let values = [
+            ("3", I32StrVec::inject(3)),
+            ("\"hi\"", I32StrVec::inject(String::from("hi"))),
+            ("[true]", I32StrVec::inject(vec![true])),
+        ];
+        for (json, expected) in values {
+            let Untagged(co) = serde_json::from_str::<Untagged<I32StrVec>>(json).unwrap();
+            assert_eq!(co, expected);
+        }
+
+        assert!(serde_json::from_str::<Untagged<I32StrVec>>("1.5").is_err());
+    }
+
+    #[test]
+    fn test_untagged_tries_variants_in_order() {
+        type U8I64 = Coprod!(u8, i64);
+        let Untagged(co) = serde_json::from_str::<Untagged<U8I64>>("3").unwrap();
+        assert_eq!(co, U8I64::inject(3u8));
+        let Untagged(co) = serde_json::from_str::<Untagged<U8I64>>("300").unwrap();
+        assert_eq!(co, U8I64::inject(300i64));
+    }
+
+    #[test]
+    fn test_untagged_128_bit_integers() {
+        use serde::de::IntoDeserializer;
+
+        type I128OrU128 = Coprod!(String, i128, u128);
+        let de: de::value::I128Deserializer<de::value::Error> = i128::MIN.into_deserializer();
+        let co = untagged::deserialize::<I128OrU128, _>(de).unwrap();
+        assert_eq!(co, I128OrU128::inject(i128::MIN));
+        let de: de::value::U128Deserializer<de::value::Error> = u128::MAX.into_deserializer();
+        let co = untagged::deserialize::<I128OrU128, _>(de).unwrap();
+        assert_eq!(co, I128OrU128::inject(u128::MAX));
+    }
+
+    #[test]
+    fn test_untagged_reports_every_variant_error() {
+        type U8Bool = Coprod!(u8, bool);
+        let error = serde_json::from_str::<Untagged<U8Bool>>("\"text\"")
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("data did not match any variant of the coproduct"));
+        assert!(error.contains("u8: invalid type: string \"text\", expected u8"));
+        assert!(error.contains("bool: invalid type: string \"text\", expected a boolean"));
+    }
+
+    #[test]
+    fn test_untagged_buffers_nested_data() {
+        type ShapeUnitOpt = Coprod!(Shape, Unit, Option<u8>);
+
+        let values = [
+            ("\"Dot\"", ShapeUnitOpt::inject(Shape::Dot)),
+            ("{\"Circle\":1.5}", ShapeUnitOpt::inject(Shape::Circle(1.5))),
+            (
+                "{\"Rect\":{\"w\":1,\"h\":2}}",
+                ShapeUnitOpt::inject(Shape::Rect { w: 1, h: 2 }),
+            ),
+            ("null", ShapeUnitOpt::inject(Unit)),
+            ("7", ShapeUnitOpt::inject(Some(7u8))),
+        ];
+        for (json, expected) in values {
+            let Untagged(co) = serde_json::from_str::<Untagged<ShapeUnitOpt>>(json).unwrap();
+            assert_eq!(co, expected);
+        }
+    }
+
+    #[test]
+    fn test_index_tagged_round_trip() {
+        let co = I32StrVec::inject(String::from("hi"));
+        let json = serde_json::to_string(&IndexTagged(co.clone())).unwrap();
+        assert_eq!(json, r#"{"index":1,"value":"hi"}"#);
+        let IndexTagged(back) = serde_json::from_str::<IndexTagged<I32StrVec>>(&json).unwrap();
+        assert_eq!(back, co);
+
+        // value before tag, sequence form, and unknown fields
+        let IndexTagged(back) =
+            serde_json::from_str::<IndexTagged<I32StrVec>>(r#"{"value":"hi","index":1}"#).unwrap();
+        assert_eq!(back, co);
+        let IndexTagged(back) =
+            serde_json::from_str::<IndexTagged<I32StrVec>>(r#"[1,"hi"]"#).unwrap();
+        assert_eq!(back, co);
+        let IndexTagged(back) =
+            serde_json::from_str::<IndexTagged<I32StrVec>>(r#"{"x":0,"index":1,"value":"hi"}"#)
+                .unwrap();
+        assert_eq!(back, co);
+
+        assert!(
+            serde_json::from_str::<IndexTagged<I32StrVec>>(r#"{"index":3,"value":1}"#).is_err()
+        );
+        assert!(serde_json::from_str::<IndexTagged<I32StrVec>>(r#"{"index":0}"#).is_err());
+    }
+
+    #[test]
+    fn test_type_name_tagged_round_trip() {
+        let co = I32StrVec::inject(5);
+        let json = serde_json::to_string(&TypeNameTagged(co.clone())).unwrap();
+        assert_eq!(json, r#"{"type":"i32","value":5}"#);
+        let TypeNameTagged(back) =
+            serde_json::from_str::<TypeNameTagged<I32StrVec>>(&json).unwrap();
+        assert_eq!(back, co);
+
+        assert!(
+            serde_json::from_str::<TypeNameTagged<I32StrVec>>(r#"{"type":"u8","value":5}"#)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_serde_with_fields() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Message {
+            #[serde(with = "untagged")]
+            a: Coprod!(bool, String),
+            #[serde(with = "index_tagged")]
+            b: Coprod!(bool, String),
+            #[serde(with = "type_name_tagged")]
+            c: Coprod!(bool, String),
+        }
+
+        let message = Message {
+            a: Coproduct::inject(true),
+            b: Coproduct::inject(String::from("b")),
+            c: Coproduct::inject(false),
+        };
+        let json = serde_json::to_string(&message).unwrap();
+        assert_eq!(
+            json,
+            r#"{"a":true,"b":{"index":1,"value":"b"},"c":{"type":"bool","value":false}}"#
+        );
+        assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), message);
+    }
+}
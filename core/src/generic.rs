@@ -33,6 +33,10 @@
 
 use crate::coproduct::Coproduct;
 use crate::hlist::HNil;
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Cow, ToOwned};
+use core::cmp::Ordering;
+use core::ops::{Bound, Range, RangeInclusive};
 
 /// A trait that converts from a type to a generic representation.
 ///
@@ -127,6 +131,12 @@ type UnaryVariant<T> = crate::HList!(T);
 type GenericOptionRepr<T> = crate::Coprod!(HNil, UnaryVariant<T>);
 type GenericResultRepr<T, E> = crate::Coprod!(UnaryVariant<T>, UnaryVariant<E>);
 type GenericBoolRepr = crate::Coprod!(HNil, HNil);
+type GenericOrderingRepr = crate::Coprod!(HNil, HNil, HNil);
+type GenericBoundRepr<T> = crate::Coprod!(UnaryVariant<T>, UnaryVariant<T>, HNil);
+type GenericRangeRepr<Idx> = crate::HList!(Idx, Idx);
+#[cfg(feature = "alloc")]
+type GenericCowRepr<'a, B> =
+    crate::Coprod!(UnaryVariant<&'a B>, UnaryVariant<<B as ToOwned>::Owned>);
 
 impl<T> Generic for Option<T> {
     type Repr = GenericOptionRepr<T>;
@@ -191,6 +201,112 @@ impl Generic for bool {
     }
 }
 
+impl Generic for Ordering {
+    type Repr = GenericOrderingRepr;
+
+    #[inline(always)]
+    fn into(self) -> Self::Repr {
+        match self {
+            Ordering::Less => Coproduct::Inl(crate::hlist![]),
+            Ordering::Equal => Coproduct::Inr(Coproduct::Inl(crate::hlist![])),
+            Ordering::Greater => Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(crate::hlist![]))),
+        }
+    }
+
+    #[inline(always)]
+    fn from(repr: Self::Repr) -> Self {
+        match repr {
+            Coproduct::Inl(crate::hlist_pat![]) => Ordering::Less,
+            Coproduct::Inr(Coproduct::Inl(crate::hlist_pat![])) => Ordering::Equal,
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(crate::hlist_pat![]))) => {
+                Ordering::Greater
+            }
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(cnil))) => match cnil {},
+        }
+    }
+}
+
+impl<T> Generic for Bound<T> {
+    type Repr = GenericBoundRepr<T>;
+
+    #[inline(always)]
+    fn into(self) -> Self::Repr {
+        match self {
+            Bound::Included(value) => Coproduct::Inl(crate::hlist![value]),
+            Bound::Excluded(value) => Coproduct::Inr(Coproduct::Inl(crate::hlist![value])),
+            Bound::Unbounded => Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(crate::hlist![]))),
+        }
+    }
+
+    #[inline(always)]
+    fn from(repr: Self::Repr) -> Self {
+        match repr {
+            Coproduct::Inl(crate::hlist_pat![value]) => Bound::Included(value),
+            Coproduct::Inr(Coproduct::Inl(crate::hlist_pat![value])) => Bound::Excluded(value),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(crate::hlist_pat![]))) => Bound::Unbounded,
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(cnil))) => match cnil {},
+        }
+    }
+}
+
+impl<Idx> Generic for Range<Idx> {
+    type Repr = GenericRangeRepr<Idx>;
+
+    #[inline(always)]
+    fn into(self) -> Self::Repr {
+        crate::hlist![self.start, self.end]
+    }
+
+    #[inline(always)]
+    fn from(repr: Self::Repr) -> Self {
+        let crate::hlist_pat![start, end] = repr;
+        start..end
+    }
+}
+
+/// The representation holds the `start` and `end` bounds. A range that has been
+/// exhausted by iteration comes back as a fresh range over the same bounds.
+impl<Idx> Generic for RangeInclusive<Idx> {
+    type Repr = GenericRangeRepr<Idx>;
+
+    #[inline(always)]
+    fn into(self) -> Self::Repr {
+        let (start, end) = self.into_inner();
+        crate::hlist![start, end]
+    }
+
+    #[inline(always)]
+    fn from(repr: Self::Repr) -> Self {
+        let crate::hlist_pat![start, end] = repr;
+        start..=end
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, B> Generic for Cow<'a, B>
+where
+    B: 'a + ToOwned + ?Sized,
+{
+    type Repr = GenericCowRepr<'a, B>;
+
+    #[inline(always)]
+    fn into(self) -> Self::Repr {
+        match self {
+            Cow::Borrowed(value) => Coproduct::Inl(crate::hlist![value]),
+            Cow::Owned(value) => Coproduct::Inr(Coproduct::Inl(crate::hlist![value])),
+        }
+    }
+
+    #[inline(always)]
+    fn from(repr: Self::Repr) -> Self {
+        match repr {
+            Coproduct::Inl(crate::hlist_pat![value]) => Cow::Borrowed(value),
+            Coproduct::Inr(Coproduct::Inl(crate::hlist_pat![value])) => Cow::Owned(value),
+            Coproduct::Inr(Coproduct::Inr(cnil)) => match cnil {},
+        }
+    }
+}
+
 /// Given a generic representation `Repr` of a `Dst`, returns `Dst`.
 pub fn from_generic<Dst, Repr>(repr: Repr) -> Dst
 where
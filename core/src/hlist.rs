@@ -845,6 +845,55 @@ where
     }
 }
 
+/// Trait for splitting an HList that was built by appending a `Suffix` to
+/// `Self` with `+` back into its two halves.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// use frunk_core::{hlist, HList};
+/// use frunk_core::hlist::HSplit;
+///
+/// let combined = hlist![1, "two"] + hlist![3.0, '4'];
+/// let (prefix, suffix) = <HList![i32, &str] as HSplit<_>>::split(combined);
+/// assert_eq!(prefix, hlist![1, "two"]);
+/// assert_eq!(suffix, hlist![3.0, '4']);
+/// # }
+/// ```
+pub trait HSplit<Suffix>: Add<Suffix> + Sized {
+    /// Split an HList into the `Self` prefix and the `Suffix` that was appended to it.
+    fn split(combined: <Self as Add<Suffix>>::Output) -> (Self, Suffix);
+}
+
+impl<Suffix> HSplit<Suffix> for HNil
+where
+    Suffix: HList,
+{
+    #[inline(always)]
+    fn split(combined: Suffix) -> (Self, Suffix) {
+        (HNil, combined)
+    }
+}
+
+impl<H, T, Suffix> HSplit<Suffix> for HCons<H, T>
+where
+    T: HSplit<Suffix>,
+    Suffix: HList,
+{
+    #[inline(always)]
+    fn split(combined: <Self as Add<Suffix>>::Output) -> (Self, Suffix) {
+        let (tail, suffix) = T::split(combined.tail);
+        (
+            HCons {
+                head: combined.head,
+                tail,
+            },
+            suffix,
+        )
+    }
+}
+
 /// Trait for borrowing an HList element by type
 ///
 /// This trait is part of the implementation of the inherent method
@@ -1746,6 +1795,18 @@ mod tests {
         assert_eq!(combined, hlist![true, "hi", 1, 32f32])
     }
 
+    #[test]
+    fn test_split() {
+        let combined = hlist![true, "hi"] + hlist![1, 32f32];
+        let (h1, h2) = <HList![bool, &str] as HSplit<_>>::split(combined);
+        assert_eq!(h1, hlist![true, "hi"]);
+        assert_eq!(h2, hlist![1, 32f32]);
+
+        let (empty, all) = <HNil as HSplit<_>>::split(hlist![1, 2]);
+        assert_eq!(empty, HNil);
+        assert_eq!(all, hlist![1, 2]);
+    }
+
     #[test]
     fn test_into_reverse() {
         let h1 = hlist![true, "hi"];
@@ -1813,7 +1874,7 @@ mod tests {
                 .chain((0..=10).map(|d| d.dummy()))
                 .chain([0_i32, 1, 2].iter().map(|d| d.dummy()))
                 .chain(['a', 'b', 'c'].iter().map(|d| d.dummy()))
-                .sum()
+                .sum::<i32>()
         );
     }
 
@@ -1872,7 +1933,7 @@ mod tests {
                 .chain((0..=10).map(|d| d.dummy()))
                 .chain([0_i32, 1, 2].iter().map(|d| d.dummy()))
                 .chain(['a', 'b', 'c'].iter().map(|d| d.dummy()))
-                .sum()
+                .sum::<i32>()
         );
     }
 
@@ -155,8 +155,19 @@ use chars::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Cow, ToOwned};
+use core::cmp::Ordering;
 use core::fmt;
 use core::marker::PhantomData;
+use core::ops::{Bound, Range, RangeInclusive};
+
+#[cfg(feature = "alloc")]
+pub mod diff;
+mod label;
+
+use self::label::{label_name, DecodedLabel, MAX_LABEL_LEN};
+pub use self::label::{Label, LabelChar};
 
 /// A trait that converts from a type to a labelled generic representation.
 ///
@@ -226,6 +237,70 @@ pub trait LabelledGeneric {
         <Self as LabelledGeneric>::from(repr)
     }
 
+    /// Returns the names of the fields of `Self`, in order, without needing a value.
+    ///
+    /// The names are computed at compile time from `Repr`, so this is available for
+    /// structs, whose representation is an HList of `Field`s.
+    ///
+    /// This fails to compile for structs with more than 128 fields, and for fields whose
+    /// label is longer than 64 characters of [`chars`], where each character that is
+    /// encoded as a unicode escape takes several of them (`é` is `_uc, u, e, _9, uc_`).
+    /// With `#[frunk(packed_labels)]`, names can instead be up to 256 bytes long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk::LabelledGeneric;
+    ///
+    /// #[derive(LabelledGeneric)]
+    /// struct User<'a> {
+    ///     first_name: &'a str,
+    ///     age: usize,
+    /// }
+    ///
+    /// assert_eq!(User::field_names(), ["first_name", "age"]);
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn field_names() -> &'static [&'static str]
+    where
+        Self::Repr: FieldNames,
+    {
+        <Self::Repr as FieldNames>::NAMES
+    }
+
+    /// Calls `f` with the name and the type name of each field of `Self`, in order,
+    /// without needing a value.
+    ///
+    /// Labels have the same length limit as for [`field_names`](Self::field_names), but
+    /// there is no limit on the number of fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() {
+    /// use frunk::LabelledGeneric;
+    ///
+    /// #[derive(LabelledGeneric)]
+    /// struct User<'a> {
+    ///     first_name: &'a str,
+    ///     age: usize,
+    /// }
+    ///
+    /// let mut columns = Vec::new();
+    /// User::for_each_field_type(&mut |name, ty| columns.push(format!("{} {}", name, ty)));
+    /// assert_eq!(columns, ["first_name &str", "age usize"]);
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn for_each_field_type<F: FnMut(&'static str, &'static str)>(f: &mut F)
+    where
+        Self::Repr: FieldNames,
+    {
+        <Self::Repr as FieldNames>::for_each_field_type(f)
+    }
+
     /// Converts from another type A into Self assuming that A and Self have
     /// labelled generic representations that can be sculpted into each other.
     ///
@@ -284,6 +359,48 @@ where
     }
 }
 
+/// A type whose references have labelled generic representations, which the
+/// `LabelledGeneric` derive provides for structs and enums.
+///
+/// This is implemented for every `T` where `&'a T` and `&'a mut T` implement
+/// `IntoLabelledGeneric`. Bounding on `T: LabelledGenericRef<'a>` instead of on
+/// `&'a T: IntoLabelledGeneric` keeps the bound on `T` itself: while `T` is not
+/// inferred yet, rustc gives up on it right away, instead of searching through
+/// ever more deeply nested `&Wrapper<Wrapper<...>>` impls until it overflows. The
+/// derive uses it for the flattened fields of generic structs.
+pub trait LabelledGenericRef<'a>: 'a {
+    /// The labelled generic representation of `&'a Self`.
+    type Ref;
+
+    /// The labelled generic representation of `&'a mut Self`.
+    type Mut;
+
+    /// Convert a reference to its representation type `Ref`.
+    fn to_repr_ref(&'a self) -> Self::Ref;
+
+    /// Convert a mutable reference to its representation type `Mut`.
+    fn to_repr_mut(&'a mut self) -> Self::Mut;
+}
+
+impl<'a, T: 'a> LabelledGenericRef<'a> for T
+where
+    &'a T: IntoLabelledGeneric,
+    &'a mut T: IntoLabelledGeneric,
+{
+    type Ref = <&'a T as IntoLabelledGeneric>::Repr;
+    type Mut = <&'a mut T as IntoLabelledGeneric>::Repr;
+
+    #[inline(always)]
+    fn to_repr_ref(&'a self) -> Self::Ref {
+        IntoLabelledGeneric::into(self)
+    }
+
+    #[inline(always)]
+    fn to_repr_mut(&'a mut self) -> Self::Mut {
+        IntoLabelledGeneric::into(self)
+    }
+}
+
 type NoneLabel = (N, o, n, e);
 type SomeLabel = (S, o, m, e);
 type OkLabel = (O, k);
@@ -298,6 +415,34 @@ type LabelledOptionRepr<T> =
 type LabelledResultRepr<T, E> =
     crate::Coprod!(UnaryTupleVariant<OkLabel, T>, UnaryTupleVariant<ErrLabel, E>);
 type LabelledBoolRepr = crate::Coprod!(UnitVariant<FalseLabel>, UnitVariant<TrueLabel>);
+type LessLabel = (L, e, s, s);
+type EqualLabel = (E, q, u, a, l);
+type GreaterLabel = (G, r, e, a, t, e, r);
+type IncludedLabel = (I, n, c, l, u, d, e, d);
+type ExcludedLabel = (E, x, c, l, u, d, e, d);
+type UnboundedLabel = (U, n, b, o, u, n, d, e, d);
+type StartLabel = (s, t, a, r, t);
+type EndLabel = (e, n, d);
+#[cfg(feature = "alloc")]
+type BorrowedLabel = (B, o, r, r, o, w, e, d);
+#[cfg(feature = "alloc")]
+type OwnedLabel = (O, w, n, e, d);
+type LabelledOrderingRepr = crate::Coprod!(
+    UnitVariant<LessLabel>,
+    UnitVariant<EqualLabel>,
+    UnitVariant<GreaterLabel>
+);
+type LabelledBoundRepr<T> = crate::Coprod!(
+    UnaryTupleVariant<IncludedLabel, T>,
+    UnaryTupleVariant<ExcludedLabel, T>,
+    UnitVariant<UnboundedLabel>
+);
+type LabelledRangeRepr<Idx> = crate::HList!(Field<StartLabel, Idx>, Field<EndLabel, Idx>);
+#[cfg(feature = "alloc")]
+type LabelledCowRepr<'a, B> = crate::Coprod!(
+    UnaryTupleVariant<BorrowedLabel, &'a B>,
+    UnaryTupleVariant<OwnedLabel, <B as ToOwned>::Owned>
+);
 
 #[inline(always)]
 fn labelled_tuple_field_0<T>(value: T) -> Field<TupleField0, T> {
@@ -403,6 +548,165 @@ impl LabelledGeneric for bool {
     }
 }
 
+impl LabelledGeneric for Ordering {
+    type Repr = LabelledOrderingRepr;
+
+    #[inline(always)]
+    fn into(self) -> Self::Repr {
+        match self {
+            Ordering::Less => Coproduct::Inl(labelled_unit_variant::<LessLabel>("Less")),
+            Ordering::Equal => {
+                Coproduct::Inr(Coproduct::Inl(labelled_unit_variant::<EqualLabel>("Equal")))
+            }
+            Ordering::Greater => {
+                Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(labelled_unit_variant::<
+                    GreaterLabel,
+                >("Greater"))))
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn from(repr: Self::Repr) -> Self {
+        match repr {
+            Coproduct::Inl(Field {
+                value: crate::hlist_pat![],
+                ..
+            }) => Ordering::Less,
+            Coproduct::Inr(Coproduct::Inl(Field {
+                value: crate::hlist_pat![],
+                ..
+            })) => Ordering::Equal,
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(Field {
+                value: crate::hlist_pat![],
+                ..
+            }))) => Ordering::Greater,
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(cnil))) => match cnil {},
+        }
+    }
+}
+
+impl<T> LabelledGeneric for Bound<T> {
+    type Repr = LabelledBoundRepr<T>;
+
+    #[inline(always)]
+    fn into(self) -> Self::Repr {
+        match self {
+            Bound::Included(value) => Coproduct::Inl(labelled_unary_tuple_variant::<
+                IncludedLabel,
+                _,
+            >("Included", value)),
+            Bound::Excluded(value) => {
+                Coproduct::Inr(Coproduct::Inl(labelled_unary_tuple_variant::<
+                    ExcludedLabel,
+                    _,
+                >("Excluded", value)))
+            }
+            Bound::Unbounded => {
+                Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(labelled_unit_variant::<
+                    UnboundedLabel,
+                >(
+                    "Unbounded"
+                ))))
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn from(repr: Self::Repr) -> Self {
+        match repr {
+            Coproduct::Inl(Field {
+                value: crate::hlist_pat![Field { value, .. }],
+                ..
+            }) => Bound::Included(value),
+            Coproduct::Inr(Coproduct::Inl(Field {
+                value: crate::hlist_pat![Field { value, .. }],
+                ..
+            })) => Bound::Excluded(value),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(Field {
+                value: crate::hlist_pat![],
+                ..
+            }))) => Bound::Unbounded,
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(cnil))) => match cnil {},
+        }
+    }
+}
+
+impl<Idx> LabelledGeneric for Range<Idx> {
+    type Repr = LabelledRangeRepr<Idx>;
+
+    #[inline(always)]
+    fn into(self) -> Self::Repr {
+        crate::hlist![
+            crate::field!(StartLabel, self.start, "start"),
+            crate::field!(EndLabel, self.end, "end"),
+        ]
+    }
+
+    #[inline(always)]
+    fn from(repr: Self::Repr) -> Self {
+        let crate::hlist_pat![start, end] = repr;
+        start.value..end.value
+    }
+}
+
+/// Like its `Generic` implementation, this does not preserve whether the range
+/// has been exhausted by iteration.
+impl<Idx> LabelledGeneric for RangeInclusive<Idx> {
+    type Repr = LabelledRangeRepr<Idx>;
+
+    #[inline(always)]
+    fn into(self) -> Self::Repr {
+        let (start, end) = self.into_inner();
+        crate::hlist![
+            crate::field!(StartLabel, start, "start"),
+            crate::field!(EndLabel, end, "end"),
+        ]
+    }
+
+    #[inline(always)]
+    fn from(repr: Self::Repr) -> Self {
+        let crate::hlist_pat![start, end] = repr;
+        start.value..=end.value
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, B> LabelledGeneric for Cow<'a, B>
+where
+    B: 'a + ToOwned + ?Sized,
+{
+    type Repr = LabelledCowRepr<'a, B>;
+
+    #[inline(always)]
+    fn into(self) -> Self::Repr {
+        match self {
+            Cow::Borrowed(value) => Coproduct::Inl(
+                labelled_unary_tuple_variant::<BorrowedLabel, _>("Borrowed", value),
+            ),
+            Cow::Owned(value) => Coproduct::Inr(Coproduct::Inl(labelled_unary_tuple_variant::<
+                OwnedLabel,
+                _,
+            >("Owned", value))),
+        }
+    }
+
+    #[inline(always)]
+    fn from(repr: Self::Repr) -> Self {
+        match repr {
+            Coproduct::Inl(Field {
+                value: crate::hlist_pat![Field { value, .. }],
+                ..
+            }) => Cow::Borrowed(value),
+            Coproduct::Inr(Coproduct::Inl(Field {
+                value: crate::hlist_pat![Field { value, .. }],
+                ..
+            })) => Cow::Owned(value),
+            Coproduct::Inr(Coproduct::Inr(cnil)) => match cnil {},
+        }
+    }
+}
+
 /// Given a labelled generic representation of a `Dst`, returns `Dst`
 pub fn from_labelled_generic<Dst, Repr>(repr: Repr) -> Dst
 where
@@ -459,6 +763,145 @@ where
     <Dst as LabelledGeneric>::transform_from(src)
 }
 
+/// Compares two values of possibly different types whose labelled generic representations
+/// have the same labels, in any order, by comparing the values of their same-named fields.
+///
+/// Fields are compared with `PartialEq`, so nested fields must either have the same type or
+/// implement `PartialEq` for each other; they are not compared structurally in turn.
+///
+/// The "Indices" type parameter allows the compiler to figure out where each field of `a` is
+/// in `b`.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// use frunk::labelled::structural_eq;
+/// use frunk_derives::LabelledGeneric;
+///
+/// #[derive(LabelledGeneric)]
+/// struct ApiUser {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// #[derive(LabelledGeneric)]
+/// struct DbUser<'a> {
+///     name: &'a str,
+///     id: u64,
+/// }
+///
+/// let api_user = ApiUser { id: 1, name: "Joe".to_string() };
+/// assert!(structural_eq(&api_user, &DbUser { name: "Joe", id: 1 }));
+/// assert!(!structural_eq(&api_user, &DbUser { name: "Jim", id: 1 }));
+/// # }
+/// ```
+pub fn structural_eq<'a, 'b, A, B, Indices>(a: &'a A, b: &'b B) -> bool
+where
+    &'a A: IntoLabelledGeneric,
+    &'b B: IntoLabelledGeneric,
+    <&'a A as IntoLabelledGeneric>::Repr:
+        StructuralEq<<&'b B as IntoLabelledGeneric>::Repr, Indices>,
+{
+    IntoLabelledGeneric::into(a).structural_eq(IntoLabelledGeneric::into(b))
+}
+
+/// A type-level label that packs the UTF-8 bytes of a name into `u128` const generics, 16
+/// bytes at a time, padding the last ones with zeros.
+///
+/// This is an alternative to the tuples of [`chars`] that keeps the names of labelled
+/// types short, and makes wide structs faster to compile. The `LabelledGeneric` derive
+/// uses it for the labels of a type marked with `#[frunk(packed_labels)]`, and the
+/// `packed_path!`/`PackedPath!` macros for the labels of their paths. Labels written by
+/// hand for the `field!` macro can be either, but only labels in the same encoding match,
+/// so the built-in `LabelledGeneric` instances, which use tuples, only convert to and from
+/// types that do not pack their labels.
+///
+/// Since the padding is zeros, names that end with NUL bytes lose them in [`Label::NAME`].
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// use frunk::labelled::{Label, Packed};
+///
+/// // "name", then 12 bytes of padding
+/// type Name = Packed<0x6e616d65_000000000000000000000000>;
+/// assert_eq!(Name::NAME, "name");
+///
+/// // "a_rather_long_name" is 18 bytes, so it takes two chunks
+/// type LongName = Packed<
+///     0x615f7261746865725f6c6f6e675f6e61,
+///     Packed<0x6d650000000000000000000000000000>,
+/// >;
+/// assert_eq!(LongName::NAME, "a_rather_long_name");
+/// # }
+/// ```
+#[derive(PartialEq, Debug, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+pub struct Packed<const BYTES: u128, Rest = ()>(PhantomData<Rest>);
+
+/// Unpacks a chunk of a [`Packed`] label, followed by the name of the rest of the label,
+/// which is empty for the last chunk.
+const fn unpack_label(bytes: u128, rest: &str) -> ([u8; MAX_LABEL_LEN], usize) {
+    let bytes = bytes.to_be_bytes();
+    let mut len = bytes.len();
+    if rest.is_empty() {
+        while len > 0 && bytes[len - 1] == 0 {
+            len -= 1;
+        }
+    }
+    let rest = rest.as_bytes();
+    assert!(
+        len + rest.len() <= MAX_LABEL_LEN,
+        "packed label is too long"
+    );
+    let mut name = [0; MAX_LABEL_LEN];
+    let mut i = 0;
+    while i < len {
+        name[i] = bytes[i];
+        i += 1;
+    }
+    let mut j = 0;
+    while j < rest.len() {
+        name[len + j] = rest[j];
+        j += 1;
+    }
+    (name, len + rest.len())
+}
+
+impl<const BYTES: u128> DecodedLabel<Packed<BYTES>> {
+    const BUF: ([u8; MAX_LABEL_LEN], usize) = unpack_label(BYTES, "");
+}
+
+impl<const BYTES: u128, const NEXT: u128, Rest> DecodedLabel<Packed<BYTES, Packed<NEXT, Rest>>>
+where
+    Packed<NEXT, Rest>: Label,
+{
+    const BUF: ([u8; MAX_LABEL_LEN], usize) =
+        unpack_label(BYTES, <Packed<NEXT, Rest> as Label>::NAME);
+}
+
+impl<const BYTES: u128> Label for Packed<BYTES> {
+    const NAME: &'static str = label_name(
+        DecodedLabel::<Self>::BUF
+            .0
+            .split_at(DecodedLabel::<Self>::BUF.1)
+            .0,
+    );
+}
+
+impl<const BYTES: u128, const NEXT: u128, Rest> Label for Packed<BYTES, Packed<NEXT, Rest>>
+where
+    Packed<NEXT, Rest>: Label,
+{
+    const NAME: &'static str = label_name(
+        DecodedLabel::<Self>::BUF
+            .0
+            .split_at(DecodedLabel::<Self>::BUF.1)
+            .0,
+    );
+}
+
 pub mod chars {
     //! Types for building type-level labels from character sequences.
     //!
@@ -482,6 +925,26 @@ pub mod chars {
         }
     }
 
+    macro_rules! impl_label_char_for {
+        ($($i: ident)*) => {
+            $(
+                impl super::LabelChar for $i {
+                    // The character is the last one in the name of the enum, so
+                    // `a` is 'a', `_1` is '1' and `__` is '_'.
+                    const BYTE: u8 = {
+                        let name = stringify!($i).as_bytes();
+                        name[name.len() - 1]
+                    };
+                }
+
+                impl super::Label for $i {
+                    const NAME: &'static str =
+                        super::label_name(&[<$i as super::LabelChar>::BYTE]);
+                }
+            )*
+        }
+    }
+
     // Add more as needed.
     create_enums_for! {
         // all valid identifier characters
@@ -490,6 +953,20 @@ pub mod chars {
         _1 _2 _3 _4 _5 _6 _7 _8 _9 _0 __ _uc uc_
     }
 
+    impl_label_char_for! {
+        a b c d e f g h i j k l m n o p q r s t u v w x y z
+        A B C D E F G H I J K L M N O P Q R S T U V W X Y Z
+        _1 _2 _3 _4 _5 _6 _7 _8 _9 _0 __
+    }
+
+    impl super::LabelChar for _uc {
+        const BYTE: u8 = b'{';
+    }
+
+    impl super::LabelChar for uc_ {
+        const BYTE: u8 = b'}';
+    }
+
     #[test]
     fn simple_var_names_are_allowed() {
         // Rust forbids variable bindings that shadow unit structs,
@@ -795,6 +1272,121 @@ where
     }
 }
 
+/// Trait for comparing an HList of `Field`s with another HList of `Field`s that has the same
+/// labels, in any order, by comparing the values of same-named fields.
+///
+/// This trait is part of the implementation of [`structural_eq`]. Please see that function
+/// for more information.
+#[diagnostic::on_unimplemented(
+    message = "Cannot structurally compare `{Self}` with `{Other}`",
+    label = "Fields do not line up with the other type",
+    note = "Both types must have fields with the same names, in any order, and no extra fields.",
+    note = "Each field must be comparable with `PartialEq` to the field with the same name in the other type.",
+    note = "Look for a field name in `{Self}` that is missing from `{Other}`, or the other way around."
+)]
+pub trait StructuralEq<Other, Indices> {
+    /// Returns whether every field of `self` equals the field with the same name in `other`.
+    fn structural_eq(self, other: Other) -> bool;
+}
+
+impl<Other> StructuralEq<Other, HNil> for HNil
+where
+    Other: NoFieldsLeft,
+{
+    #[inline(always)]
+    fn structural_eq(self, _: Other) -> bool {
+        true
+    }
+}
+
+/// Implemented only by `HNil`, to point at the fields left over on one side of a
+/// [`StructuralEq`] comparison once every field of the other side has been matched.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has fields that the other type does not have",
+    label = "Extra fields in the compared type",
+    note = "Both types must have fields with the same names, in any order, and no extra fields."
+)]
+pub trait NoFieldsLeft {}
+
+impl NoFieldsLeft for HNil {}
+
+impl<K, V, Tail, Other, HeadIndex, TailIndices> StructuralEq<Other, HCons<HeadIndex, TailIndices>>
+    for HCons<Field<K, V>, Tail>
+where
+    Other: ByNameFieldPlucker<K, HeadIndex>,
+    V: PartialEq<<Other as ByNameFieldPlucker<K, HeadIndex>>::TargetValue>,
+    Tail: StructuralEq<<Other as ByNameFieldPlucker<K, HeadIndex>>::Remainder, TailIndices>,
+{
+    #[inline(always)]
+    fn structural_eq(self, other: Other) -> bool {
+        let (field, remainder) = other.pluck_by_name();
+        self.head.value == field.value && self.tail.structural_eq(remainder)
+    }
+}
+
+/// Trait for the names of the fields in an HList of `Field`s, like the labelled generic
+/// representation of a struct, known without having a value.
+///
+/// Types that implement `LabelledGeneric` expose this through
+/// [`LabelledGeneric::field_names`] and [`LabelledGeneric::for_each_field_type`].
+pub trait FieldNames {
+    /// The names of the fields, in order.
+    ///
+    /// This fails to compile for HLists of more than 128 fields.
+    const NAMES: &'static [&'static str];
+
+    /// Calls `f` with the name and the type name of each field, in order.
+    ///
+    /// Type names come from [`core::any::type_name`], so they are meant for documentation
+    /// and diagnostics rather than for being parsed.
+    fn for_each_field_type<F: FnMut(&'static str, &'static str)>(f: &mut F);
+}
+
+/// The most fields that [`FieldNames::NAMES`] can be computed for.
+const MAX_FIELD_NAMES: usize = 128;
+
+/// Holds the names of the fields of `Repr` while they are built at compile time, since
+/// the number of fields cannot be used as the length of an array yet.
+struct FieldNamesBuf<Repr>(PhantomData<Repr>);
+
+impl<K: Label, V, Tail: FieldNames> FieldNamesBuf<HCons<Field<K, V>, Tail>> {
+    const BUF: ([&'static str; MAX_FIELD_NAMES], usize) = {
+        let tail = Tail::NAMES;
+        assert!(
+            tail.len() < MAX_FIELD_NAMES,
+            "too many fields to compute their names"
+        );
+        let mut names = [""; MAX_FIELD_NAMES];
+        names[0] = K::NAME;
+        let mut i = 0;
+        while i < tail.len() {
+            names[i + 1] = tail[i];
+            i += 1;
+        }
+        (names, tail.len() + 1)
+    };
+}
+
+impl FieldNames for HNil {
+    const NAMES: &'static [&'static str] = &[];
+
+    #[inline(always)]
+    fn for_each_field_type<F: FnMut(&'static str, &'static str)>(_: &mut F) {}
+}
+
+impl<K: Label, V, Tail: FieldNames> FieldNames for HCons<Field<K, V>, Tail> {
+    const NAMES: &'static [&'static str] = FieldNamesBuf::<Self>::BUF
+        .0
+        .split_at(FieldNamesBuf::<Self>::BUF.1)
+        .0;
+
+    fn for_each_field_type<F: FnMut(&'static str, &'static str)>(f: &mut F) {
+        f(K::NAME, core::any::type_name::<V>());
+        Tail::for_each_field_type(f)
+    }
+}
+
 /// Trait for transmogrifying a `Source` type into a `Target` type.
 ///
 /// What is "transmogrifying"? In this context, it means to convert some data of type `A`
@@ -1281,6 +1873,58 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_structural_eq() {
+        let a = hlist![field!(name, "joe"), field!(age, 3)];
+        let b = hlist![field!(age, 3), field!(name, "joe".to_string())];
+        assert!(a.structural_eq(b));
+
+        let c = hlist![field!(age, 4), field!(name, "joe")];
+        assert!(!a.structural_eq(c));
+        assert!(HNil.structural_eq(HNil));
+    }
+
+    #[test]
+    fn test_label_names() {
+        assert_eq!(<a as Label>::NAME, "a");
+        assert_eq!(<(n, a, m, e)>::NAME, "name");
+        assert_eq!(<(i, s, __, A, d, m, i, n, _1)>::NAME, "is_Admin1");
+    }
+
+    #[test]
+    fn test_unicode_label_names() {
+        // encoded the way the derive does, from `char::escape_unicode`
+        type Space = (_uc, u, _2, _0, uc_);
+        type Heart = (_uc, u, _2, _7, _6, _4, uc_);
+        type Crab = (_uc, u, _1, f, _9, _8, _0, uc_);
+        assert_eq!(<Space as Label>::NAME, " ");
+        assert_eq!(<Heart as Label>::NAME, "\u{2764}");
+        assert_eq!(<Crab as Label>::NAME, "\u{1f980}");
+        assert_eq!(
+            <(f, u, l, l, _uc, u, _2, _0, uc_, n, a, m, e)>::NAME,
+            "full name"
+        );
+        assert_eq!(
+            <(_uc, u, _5, _4, _0, d, uc_, _uc, u, _5, _2, _4, d, uc_)>::NAME,
+            "名前"
+        );
+    }
+
+    #[test]
+    fn test_field_names() {
+        type Repr = HList![Field<name, &'static str>, Field<(a, g, e), i32>];
+        assert_eq!(<Repr as FieldNames>::NAMES, ["name", "age"]);
+        assert!(<HNil as FieldNames>::NAMES.is_empty());
+
+        let mut types = [""; 2];
+        let mut i = 0;
+        Repr::for_each_field_type(&mut |_, ty| {
+            types[i] = ty;
+            i += 1;
+        });
+        assert_eq!(types, ["&str", "i32"]);
+    }
+
     #[test]
     fn test_transmogrify_through_containers() {
         type SourceOuter<T> = HList![
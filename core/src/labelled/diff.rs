@@ -0,0 +1,331 @@
+//! Structural diffs between two values of a `LabelledGeneric` type.
+//!
+//! [`diff`] compares two values field by field, through the representations
+//! derived for references to them, and reports every field whose value changed
+//! as a [`FieldChange`], identified by the path of [`Field::name`]s leading to
+//! it.
+//!
+//! What counts as a field is decided by the [`Diff`] trait: fields whose types
+//! are compared as a whole (numbers, strings, `Option`s, tuples, arrays, `Vec`s,
+//! `BTreeMap`s, ...) are reported as changed when they are not equal, while fields
+//! whose types are themselves `LabelledGeneric` can be recursed into by
+//! implementing `Diff` for them with [`diff_fields_into`]. Other types, like enums,
+//! can be compared as a whole with [`diff_value_into`].
+//!
+//! `HashMap` and `HashSet` are only in `std`, which this crate does not use, so
+//! `Diff` is not implemented for them. Wrap fields of those types in a type of
+//! your own that implements `Diff` with [`diff_value_into`].
+//!
+//! # Examples
+//!
+//! ```
+//! # fn main() {
+//! use frunk_core::labelled::diff::{diff, diff_fields_into, Diff, FieldChange};
+//! use frunk_derives::LabelledGeneric;
+//!
+//! #[derive(LabelledGeneric)]
+//! struct Address {
+//!     street: String,
+//!     city: String,
+//! }
+//!
+//! #[derive(LabelledGeneric)]
+//! struct Customer {
+//!     name: String,
+//!     address: Address,
+//!     orders: u32,
+//! }
+//!
+//! impl Diff for Address {
+//!     fn diff_into(
+//!         &self,
+//!         other: &Self,
+//!         path: &mut Vec<&'static str>,
+//!         changes: &mut Vec<FieldChange>,
+//!     ) {
+//!         diff_fields_into(self, other, path, changes)
+//!     }
+//! }
+//!
+//! let before = Customer {
+//!     name: "Joe".to_string(),
+//!     address: Address { street: "Main St".to_string(), city: "Paris".to_string() },
+//!     orders: 3,
+//! };
+//! let after = Customer {
+//!     name: "Joe".to_string(),
+//!     address: Address { street: "Main St".to_string(), city: "Lyon".to_string() },
+//!     orders: 4,
+//! };
+//!
+//! let changes = diff(&before, &after);
+//! assert_eq!(changes.len(), 2);
+//! assert_eq!(changes[0].path, ["address", "city"]);
+//! assert_eq!(changes[0].to_string(), r#"address.city: "Paris" -> "Lyon""#);
+//! assert_eq!(changes[1].to_string(), "orders: 3 -> 4");
+//! # }
+//! ```
+
+use super::{Field, IntoLabelledGeneric};
+use crate::hlist::{HCons, HNil};
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::time::Duration;
+
+/// A field whose value differs between two values.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldChange {
+    /// The names of the fields leading to the changed field, outermost first.
+    pub path: Vec<&'static str>,
+    /// The `Debug` representation of the old value of the field.
+    pub old: String,
+    /// The `Debug` representation of the new value of the field.
+    pub new: String,
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.path.join("."), self.old, self.new)
+    }
+}
+
+/// Trait for finding the fields that differ between two values.
+///
+/// This is implemented for the building blocks of `LabelledGeneric`
+/// representations, for references, and for common types that are compared as a
+/// whole. See the [module documentation](self) for implementing it for your own
+/// types.
+pub trait Diff {
+    /// Pushes a [`FieldChange`] onto `changes` for every field that differs
+    /// between `self` and `other`, where `path` is the path to `self`.
+    fn diff_into(&self, other: &Self, path: &mut Vec<&'static str>, changes: &mut Vec<FieldChange>);
+}
+
+/// Returns the fields that differ between `old` and `new`, in field order.
+///
+/// See the [module documentation](self) for more information.
+pub fn diff<'a, T>(old: &'a T, new: &'a T) -> Vec<FieldChange>
+where
+    &'a T: IntoLabelledGeneric,
+    <&'a T as IntoLabelledGeneric>::Repr: Diff,
+{
+    let mut changes = Vec::new();
+    diff_fields_into(old, new, &mut Vec::new(), &mut changes);
+    changes
+}
+
+/// Implements [`Diff::diff_into`] for a `LabelledGeneric` type by comparing its
+/// fields.
+pub fn diff_fields_into<'a, T>(
+    old: &'a T,
+    new: &'a T,
+    path: &mut Vec<&'static str>,
+    changes: &mut Vec<FieldChange>,
+) where
+    &'a T: IntoLabelledGeneric,
+    <&'a T as IntoLabelledGeneric>::Repr: Diff,
+{
+    IntoLabelledGeneric::into(old).diff_into(&IntoLabelledGeneric::into(new), path, changes)
+}
+
+/// Implements [`Diff::diff_into`] for a type by comparing values as a whole.
+pub fn diff_value_into<T>(old: &T, new: &T, path: &[&'static str], changes: &mut Vec<FieldChange>)
+where
+    T: PartialEq + fmt::Debug + ?Sized,
+{
+    if old != new {
+        changes.push(FieldChange {
+            path: path.to_vec(),
+            old: format!("{:?}", old),
+            new: format!("{:?}", new),
+        });
+    }
+}
+
+impl Diff for HNil {
+    fn diff_into(&self, _: &Self, _: &mut Vec<&'static str>, _: &mut Vec<FieldChange>) {}
+}
+
+impl<H: Diff, T: Diff> Diff for HCons<H, T> {
+    fn diff_into(
+        &self,
+        other: &Self,
+        path: &mut Vec<&'static str>,
+        changes: &mut Vec<FieldChange>,
+    ) {
+        self.head.diff_into(&other.head, path, changes);
+        self.tail.diff_into(&other.tail, path, changes);
+    }
+}
+
+impl<Name, Type: Diff> Diff for Field<Name, Type> {
+    fn diff_into(
+        &self,
+        other: &Self,
+        path: &mut Vec<&'static str>,
+        changes: &mut Vec<FieldChange>,
+    ) {
+        path.push(self.name);
+        self.value.diff_into(&other.value, path, changes);
+        path.pop();
+    }
+}
+
+impl<T: Diff + ?Sized> Diff for &T {
+    fn diff_into(
+        &self,
+        other: &Self,
+        path: &mut Vec<&'static str>,
+        changes: &mut Vec<FieldChange>,
+    ) {
+        (**self).diff_into(*other, path, changes)
+    }
+}
+
+impl<T: Diff + ?Sized> Diff for &mut T {
+    fn diff_into(
+        &self,
+        other: &Self,
+        path: &mut Vec<&'static str>,
+        changes: &mut Vec<FieldChange>,
+    ) {
+        (**self).diff_into(&**other, path, changes)
+    }
+}
+
+impl<T: Diff + ?Sized> Diff for Box<T> {
+    fn diff_into(
+        &self,
+        other: &Self,
+        path: &mut Vec<&'static str>,
+        changes: &mut Vec<FieldChange>,
+    ) {
+        (**self).diff_into(&**other, path, changes)
+    }
+}
+
+macro_rules! diff_as_value {
+    ($([$($generics:tt)*] $ty:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> Diff for $ty {
+                fn diff_into(
+                    &self,
+                    other: &Self,
+                    path: &mut Vec<&'static str>,
+                    changes: &mut Vec<FieldChange>,
+                ) {
+                    diff_value_into(self, other, path, changes)
+                }
+            }
+        )*
+    };
+}
+
+diff_as_value! {
+    [] (), [] bool, [] char, [] str, [] String, [] Duration,
+    [] i8, [] i16, [] i32, [] i64, [] i128, [] isize,
+    [] u8, [] u16, [] u32, [] u64, [] u128, [] usize,
+    [] f32, [] f64,
+    [T: PartialEq + fmt::Debug] Option<T>,
+    [T: PartialEq + fmt::Debug] Vec<T>,
+    [T: PartialEq + fmt::Debug] VecDeque<T>,
+    [T: PartialEq + fmt::Debug] [T],
+    [T: PartialEq + fmt::Debug, const N: usize] [T; N],
+    [T: PartialEq + fmt::Debug] BTreeSet<T>,
+    [K: PartialEq + fmt::Debug, V: PartialEq + fmt::Debug] BTreeMap<K, V>,
+}
+
+macro_rules! diff_tuples_as_value {
+    ($($t: ident)*) => {
+        diff_tuples_as_value!(@impl [] $($t)*);
+    };
+    (@impl [$($done: ident)*]) => {};
+    (@impl [$($done: ident)*] $next: ident $($rest: ident)*) => {
+        diff_as_value! {
+            [$($done: PartialEq + fmt::Debug,)* $next: PartialEq + fmt::Debug]
+            ($($done,)* $next,)
+        }
+        diff_tuples_as_value!(@impl [$($done)* $next] $($rest)*);
+    };
+}
+
+diff_tuples_as_value!(A B C D E F G H I J K L);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::labelled::chars::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn test_diff_hlist_of_fields() {
+        let old = hlist![
+            field!((n, a, m, e), "Joe"),
+            field!((a, g, e), 30),
+            field!((t, a, g, s), vec!["a"])
+        ];
+        let new = hlist![
+            field!((n, a, m, e), "Joe"),
+            field!((a, g, e), 31),
+            field!((t, a, g, s), vec!["a", "b"])
+        ];
+        let mut changes = Vec::new();
+        old.diff_into(&new, &mut Vec::new(), &mut changes);
+        assert_eq!(
+            changes,
+            vec![
+                FieldChange {
+                    path: vec!["age"],
+                    old: "30".to_string(),
+                    new: "31".to_string(),
+                },
+                FieldChange {
+                    path: vec!["tags"],
+                    old: r#"["a"]"#.to_string(),
+                    new: r#"["a", "b"]"#.to_string(),
+                },
+            ]
+        );
+
+        let mut unchanged = Vec::new();
+        old.diff_into(&old, &mut Vec::new(), &mut unchanged);
+        assert!(unchanged.is_empty());
+    }
+
+    #[test]
+    fn test_diff_nested_path() {
+        let old = hlist![field!((o, u, t), hlist![field!((i, n), 1.5)])];
+        let new = hlist![field!((o, u, t), hlist![field!((i, n), 2.5)])];
+        let mut changes = Vec::new();
+        old.diff_into(&new, &mut vec!["root"], &mut changes);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].to_string(), "root.out.in: 1.5 -> 2.5");
+    }
+
+    #[test]
+    fn test_diff_collections_and_tuples_as_values() {
+        let old = hlist![
+            field!((p, a, i, r), (1, "a")),
+            field!((r, g, b), [0u8, 0, 0]),
+            field!((l, i, m, i, t, s), BTreeMap::from([("daily", 1)])),
+        ];
+        let new = hlist![
+            field!((p, a, i, r), (1, "a")),
+            field!((r, g, b), [0u8, 0, 255]),
+            field!((l, i, m, i, t, s), BTreeMap::from([("daily", 2)])),
+        ];
+        let mut changes = Vec::new();
+        old.diff_into(&new, &mut Vec::new(), &mut changes);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].to_string(), "rgb: [0, 0, 0] -> [0, 0, 255]");
+        assert_eq!(
+            changes[1].to_string(),
+            r#"limits: {"daily": 1} -> {"daily": 2}"#
+        );
+    }
+}
@@ -0,0 +1,135 @@
+//! Type-level labels whose names are known at compile time.
+
+use core::marker::PhantomData;
+
+/// A type-level label, like the tuples of [`chars`](super::chars) that the `field!` macro
+/// and the `LabelledGeneric` derive use as field names, whose name is known at compile
+/// time.
+///
+/// This lets generic code print or compare the names of fields without having a `Field`
+/// at hand. Characters that the derive encodes between `_uc` and `uc_`, because they
+/// cannot be part of an identifier, are decoded back.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// use frunk::labelled::chars::*;
+/// use frunk::labelled::Label;
+///
+/// assert_eq!(<(f, i, r, s, t, __, n, a, m, e)>::NAME, "first_name");
+/// assert_eq!(<(__, _0)>::NAME, "_0");
+///
+/// // "é" is the unicode escape `\u{e9}`
+/// assert_eq!(<(c, a, f, _uc, u, e, _9, uc_)>::NAME, "café");
+/// # }
+/// ```
+pub trait Label {
+    /// The name spelled by the label.
+    const NAME: &'static str;
+}
+
+/// A character of a type-level label.
+///
+/// This is implemented for the enums in [`chars`](super::chars), and used to implement
+/// [`Label`] for tuples of them.
+#[doc(hidden)]
+pub trait LabelChar {
+    /// The character as ASCII, where `_uc` and `uc_` are the `{` and `}` around a
+    /// unicode escape.
+    const BYTE: u8;
+}
+
+/// The longest name, in bytes, that a [`Label`] can decode to. Tuples are labels up to 64
+/// characters, and no character decodes to more bytes than the characters that encode it,
+/// while longer [`Packed`](super::Packed) labels fail to compile.
+pub(super) const MAX_LABEL_LEN: usize = 256;
+
+/// Holds the decoded name of a `Label` while it is built at compile time.
+pub(super) struct DecodedLabel<L>(PhantomData<L>);
+
+pub(super) const fn label_name(bytes: &'static [u8]) -> &'static str {
+    match core::str::from_utf8(bytes) {
+        Ok(name) => name,
+        Err(_) => panic!("decoded labels are valid UTF-8"),
+    }
+}
+
+/// Decodes the characters of a label, turning the unicode escapes between `_uc` and `uc_`
+/// (a `u` then hex digits, as from `char::escape_unicode`) back into their characters.
+const fn decode_label(encoded: &[u8]) -> ([u8; MAX_LABEL_LEN], usize) {
+    let mut name = [0; MAX_LABEL_LEN];
+    let mut len = 0;
+    let mut i = 0;
+    while i < encoded.len() {
+        let mut utf8 = [0; 4];
+        let utf8_len = match encoded[i] {
+            b'{' => {
+                assert!(
+                    i + 1 < encoded.len() && encoded[i + 1] == b'u',
+                    "unicode escapes in labels start with `_uc, u`"
+                );
+                i += 2;
+                let mut code: u32 = 0;
+                while i < encoded.len() && encoded[i] != b'}' {
+                    let digit = match encoded[i] {
+                        b'0'..=b'9' => encoded[i] - b'0',
+                        b'a'..=b'f' => encoded[i] - b'a' + 10,
+                        _ => panic!("unicode escapes in labels are lowercase hex digits"),
+                    };
+                    assert!(code <= 0x10FFFF, "unicode escape in label is out of range");
+                    code = code * 16 + digit as u32;
+                    i += 1;
+                }
+                assert!(
+                    i < encoded.len(),
+                    "unicode escapes in labels end with `uc_`"
+                );
+                match char::from_u32(code) {
+                    Some(c) => c.encode_utf8(&mut utf8).len(),
+                    None => panic!("unicode escape in label is not a char"),
+                }
+            }
+            b'}' => panic!("`uc_` in label without a matching `_uc`"),
+            byte => {
+                utf8[0] = byte;
+                1
+            }
+        };
+        let mut j = 0;
+        while j < utf8_len {
+            name[len] = utf8[j];
+            len += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    (name, len)
+}
+
+macro_rules! impl_label_for_tuples {
+    ($($c: ident)*) => {
+        impl_label_for_tuples!(@impl [] $($c)*);
+    };
+    (@impl [$($done: ident)*]) => {};
+    (@impl [$($done: ident)*] $next: ident $($rest: ident)*) => {
+        impl<$($done: LabelChar,)* $next: LabelChar> DecodedLabel<($($done,)* $next,)> {
+            const BUF: ([u8; MAX_LABEL_LEN], usize) =
+                decode_label(&[$(<$done as LabelChar>::BYTE,)* <$next as LabelChar>::BYTE]);
+        }
+
+        impl<$($done: LabelChar,)* $next: LabelChar> Label for ($($done,)* $next,) {
+            const NAME: &'static str =
+                label_name(DecodedLabel::<Self>::BUF.0.split_at(DecodedLabel::<Self>::BUF.1).0);
+        }
+
+        impl_label_for_tuples!(@impl [$($done)* $next] $($rest)*);
+    };
+}
+
+impl_label_for_tuples! {
+    C1 C2 C3 C4 C5 C6 C7 C8 C9 C10 C11 C12 C13 C14 C15 C16
+    C17 C18 C19 C20 C21 C22 C23 C24 C25 C26 C27 C28 C29 C30 C31 C32
+    C33 C34 C35 C36 C37 C38 C39 C40 C41 C42 C43 C44 C45 C46 C47 C48
+    C49 C50 C51 C52 C53 C54 C55 C56 C57 C58 C59 C60 C61 C62 C63 C64
+}
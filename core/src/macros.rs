@@ -233,6 +233,39 @@ macro_rules! field {
 ///
 /// assert_eq!(folded, 1);
 /// # }
+/// ```
+///
+/// Handlers can take references too, for folding a borrowed coproduct with
+/// [`fold_ref`](crate::coproduct::Coproduct::fold_ref) or
+/// [`fold_mut`](crate::coproduct::Coproduct::fold_mut). Elided lifetimes are fine
+/// in argument types; name the lifetime with `['a]` when the output borrows from
+/// the argument. The returned value is `Copy`, so a single `poly_fn!` can serve
+/// owned and borrowed folds alike.
+///
+/// ```
+/// # fn main() {
+/// use frunk_core::{Coprod, poly_fn};
+/// type I32Str = Coprod!(i32, String);
+///
+/// let describe = poly_fn!(
+///   |n: i32| -> String { format!("owned {}", n) },
+///   |n: &i32| -> String { format!("borrowed {}", n) },
+///   |s: String| -> String { s },
+///   ['a] |s: &'a String| -> String { s.clone() },
+/// );
+///
+/// let co = I32Str::inject(3);
+/// assert_eq!(co.fold_ref(describe), "borrowed 3");
+/// assert_eq!(co.fold(describe), "owned 3");
+///
+/// let co = I32Str::inject(String::from("hi"));
+/// let borrowed: &str = co.fold_ref(poly_fn!(
+///   ['a] |_n: &'a i32| -> &'a str { "" },
+///   ['a] |s: &'a String| -> &'a str { s },
+/// ));
+/// assert_eq!(borrowed, "hi");
+/// # }
+/// ```
 #[macro_export]
 macro_rules! poly_fn {
     // encountered first func w/ type params
@@ -285,6 +318,7 @@ macro_rules! poly_fn {
 
     // unroll
     (p~ $([$($pars: tt, )*] |$p_args: ident : $p_arg_typ: ty| -> $p_ret_typ: ty $p_body: block, )* ~p f~ $(|$args: ident : $arg_typ: ty| -> $ret_typ: ty $body: block, )* ~f) => {{
+        #[derive(Clone, Copy)]
         struct F;
         $(
             impl<$($pars,)*> $crate::traits::Func<$p_arg_typ> for F {
@@ -304,6 +338,282 @@ macro_rules! poly_fn {
     }}
 }
 
+/// Implements [`UpcastFrom`](crate::coproduct::UpcastFrom) for a trait object, for
+/// every type that implements the trait.
+///
+/// This is what lets [`Coproduct::as_dyn`](crate::coproduct::Coproduct::as_dyn) view
+/// a coproduct as `dyn YourTrait`. Trait objects with extra bounds, like
+/// `dyn YourTrait + Send`, are supported as well.
+///
+/// # Examples
+///
+/// ```
+/// # use frunk_core::{impl_upcast_from, Coprod};
+/// # fn main() {
+/// trait Greet {
+///     fn greet(&self) -> String;
+/// }
+///
+/// impl Greet for i32 {
+///     fn greet(&self) -> String {
+///         format!("number {}", self)
+///     }
+/// }
+///
+/// impl Greet for bool {
+///     fn greet(&self) -> String {
+///         format!("boolean {}", self)
+///     }
+/// }
+///
+/// impl_upcast_from!(dyn Greet + Send);
+///
+/// let co = <Coprod!(i32, bool)>::inject(true);
+/// let greeter: &(dyn Greet + Send) = co.as_dyn();
+/// assert_eq!(greeter.greet(), "boolean true");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_upcast_from {
+    (dyn $($bound:tt)+) => {
+        impl<'a, T: $($bound)+ + 'a> $crate::coproduct::UpcastFrom<T> for dyn $($bound)+ + 'a {
+            fn upcast_ref(value: &T) -> &Self {
+                value
+            }
+
+            fn upcast_mut(value: &mut T) -> &mut Self {
+                value
+            }
+        }
+    };
+}
+
+/// Writes the same trait impl for each of several types.
+///
+/// `impl_for_each! { impl<..> Trait for A, B where .. { .. } }` expands to one
+/// `impl<..> Trait for A where .. { .. }` per listed type, with the same generic
+/// parameters, `where` clause and items, where `Self` is that type. The generic
+/// parameters and the `where` clause can be written as on any impl.
+///
+/// The macro derives nothing by itself: the items are pasted as they are. It saves
+/// repeating them when a trait is implemented for the building blocks of generic
+/// representations (`HNil` and `HCons`, `CNil` and `Coproduct`, and
+/// [`Field`](crate::labelled::Field)) and the impls for the types themselves only
+/// delegate to their representations, e.g. with
+/// [`into_generic`](crate::generic::into_generic),
+/// [`from_generic`](crate::generic::from_generic) or the
+/// [`IntoLabelledGeneric`](crate::labelled::IntoLabelledGeneric) impls derived for
+/// `&Self` and `&mut Self`. `Generic` has no representation for references, so
+/// `&self` methods of types that only derive `Generic` have to clone `self` first.
+///
+/// # Examples
+///
+/// ```
+/// # use frunk_core::{impl_for_each, hlist, HList};
+/// # use frunk_core::coproduct::{CNil, Coproduct};
+/// # use frunk_core::hlist::{HCons, HNil};
+/// # use frunk_core::labelled::{Field, IntoLabelledGeneric};
+/// # use frunk_derives::LabelledGeneric;
+/// # fn main() {
+/// trait Describe {
+///     fn describe(&self) -> String;
+/// }
+///
+/// impl Describe for u32 {
+///     fn describe(&self) -> String {
+///         self.to_string()
+///     }
+/// }
+///
+/// impl<T: Describe + ?Sized> Describe for &T {
+///     fn describe(&self) -> String {
+///         (**self).describe()
+///     }
+/// }
+///
+/// impl<K, V: Describe> Describe for Field<K, V> {
+///     fn describe(&self) -> String {
+///         format!("{}: {}", self.name, self.value.describe())
+///     }
+/// }
+///
+/// impl Describe for HNil {
+///     fn describe(&self) -> String {
+///         String::new()
+///     }
+/// }
+///
+/// impl<H: Describe, T: Describe> Describe for HCons<H, T> {
+///     fn describe(&self) -> String {
+///         format!("{}; {}", self.head.describe(), self.tail.describe())
+///     }
+/// }
+///
+/// impl Describe for CNil {
+///     fn describe(&self) -> String {
+///         match *self {}
+///     }
+/// }
+///
+/// impl<H: Describe, T: Describe> Describe for Coproduct<H, T> {
+///     fn describe(&self) -> String {
+///         match self {
+///             Coproduct::Inl(h) => h.describe(),
+///             Coproduct::Inr(t) => t.describe(),
+///         }
+///     }
+/// }
+///
+/// #[derive(LabelledGeneric)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// #[derive(LabelledGeneric)]
+/// enum Shape {
+///     Circle { radius: u32 },
+///     Square { side: u32 },
+/// }
+///
+/// impl_for_each! {
+///     impl Describe for Point, Shape {
+///         fn describe(&self) -> String {
+///             IntoLabelledGeneric::into(self).describe()
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Point { x: 1, y: 2 }.describe(), "x: 1; y: 2; ");
+/// assert_eq!(Shape::Square { side: 3 }.describe(), "Square: side: 3; ");
+/// # }
+/// ```
+///
+/// Items that build a `Self` go through [`from_generic`](crate::generic::from_generic),
+/// and generic types bound their representation in the `where` clause:
+///
+/// ```
+/// # use frunk_core::{impl_for_each, HList};
+/// # use frunk_core::generic::{from_generic, Generic};
+/// # use frunk_core::hlist::{HCons, HNil};
+/// # use frunk_derives::Generic;
+/// # fn main() {
+/// trait Zero {
+///     fn zero() -> Self;
+/// }
+///
+/// impl Zero for i64 {
+///     fn zero() -> Self {
+///         0
+///     }
+/// }
+///
+/// impl Zero for HNil {
+///     fn zero() -> Self {
+///         HNil
+///     }
+/// }
+///
+/// impl<H: Zero, T: Zero> Zero for HCons<H, T> {
+///     fn zero() -> Self {
+///         HCons { head: H::zero(), tail: T::zero() }
+///     }
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Pair<T>(T, T);
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Tagged<T> {
+///     tag: i64,
+///     value: T,
+/// }
+///
+/// impl_for_each! {
+///     impl<T> Zero for Pair<T>, Tagged<T>
+///     where
+///         Self: Generic,
+///         <Self as Generic>::Repr: Zero,
+///     {
+///         fn zero() -> Self {
+///             from_generic(<<Self as Generic>::Repr as Zero>::zero())
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Pair::zero(), Pair(0i64, 0));
+/// assert_eq!(Tagged::zero(), Tagged { tag: 0, value: 0i64 });
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_for_each {
+    (impl < $($rest:tt)*) => {
+        $crate::impl_for_each!(@generics [] [] $($rest)*);
+    };
+    (impl $($rest:tt)*) => {
+        $crate::impl_for_each!(@trait [] [] $($rest)*);
+    };
+
+    // Collects the generic parameters up to the `>` that closes them, keeping count of
+    // the `<` opened in between.
+    (@generics [$($params:tt)*] [] > $($rest:tt)*) => {
+        $crate::impl_for_each!(@trait [<$($params)*>] [] $($rest)*);
+    };
+    (@generics [$($params:tt)*] [$open:tt] >> $($rest:tt)*) => {
+        $crate::impl_for_each!(@trait [<$($params)* > >] [] $($rest)*);
+    };
+    (@generics [$($params:tt)*] [$open:tt $($depth:tt)*] > $($rest:tt)*) => {
+        $crate::impl_for_each!(@generics [$($params)* >] [$($depth)*] $($rest)*);
+    };
+    (@generics [$($params:tt)*] [$open:tt $open2:tt $($depth:tt)*] >> $($rest:tt)*) => {
+        $crate::impl_for_each!(@generics [$($params)* >>] [$($depth)*] $($rest)*);
+    };
+    (@generics [$($params:tt)*] [$($depth:tt)*] < $($rest:tt)*) => {
+        $crate::impl_for_each!(@generics [$($params)* <] [< $($depth)*] $($rest)*);
+    };
+    (@generics [$($params:tt)*] [$($depth:tt)*] << $($rest:tt)*) => {
+        $crate::impl_for_each!(@generics [$($params)* <<] [< < $($depth)*] $($rest)*);
+    };
+    (@generics [$($params:tt)*] [$($depth:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::impl_for_each!(@generics [$($params)* $next] [$($depth)*] $($rest)*);
+    };
+
+    // Collects the trait up to `for`.
+    (@trait $generics:tt [$($trait_:tt)*] for $($rest:tt)*) => {
+        $crate::impl_for_each!(@types $generics [$($trait_)*] $($rest)*);
+    };
+    (@trait $generics:tt [$($trait_:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::impl_for_each!(@trait $generics [$($trait_)* $next] $($rest)*);
+    };
+
+    (@types $generics:tt $trait_:tt $($ty:ty),+ $(,)? { $($items:tt)* }) => {
+        $crate::impl_for_each!(@each $generics $trait_ [] [$($ty),+] { $($items)* });
+    };
+    (@types $generics:tt $trait_:tt $($ty:ty),+ $(,)? where $($rest:tt)+) => {
+        $crate::impl_for_each!(@where $generics $trait_ [] [$($ty),+] $($rest)+);
+    };
+
+    // Collects the `where` clause up to the block of items, which is the last token.
+    (@where $generics:tt $trait_:tt [$($where_:tt)*] $tys:tt { $($items:tt)* }) => {
+        $crate::impl_for_each!(@each $generics $trait_ [where $($where_)*] $tys { $($items)* });
+    };
+    (@where $generics:tt $trait_:tt [$($where_:tt)*] $tys:tt $next:tt $($rest:tt)*) => {
+        $crate::impl_for_each!(@where $generics $trait_ [$($where_)* $next] $tys $($rest)*);
+    };
+
+    (@each $generics:tt $trait_:tt $where_:tt [$($ty:ty),+] $items:tt) => {
+        $( $crate::impl_for_each!(@one $generics $trait_ $where_ [$ty] $items); )+
+    };
+    (
+        @one [$($generics:tt)*] [$($trait_:tt)*] [$($where_:tt)*] [$ty:ty]
+        { $($items:tt)* }
+    ) => {
+        impl $($generics)* $($trait_)* for $ty $($where_)* {
+            $($items)*
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     #[allow(clippy::diverging_sub_expression)]
@@ -421,4 +731,43 @@ mod tests {
         assert_eq!(foo, "foo");
         assert_eq!(baz, "baz");
     }
+
+    #[test]
+    fn impl_for_each_generics() {
+        use alloc::string::ToString;
+        use alloc::vec::Vec;
+
+        trait Len {
+            fn len(&self) -> usize;
+        }
+
+        struct Chunk<'a, T, const N: usize>(&'a [T; N]);
+        struct Bytes<'a, T, const N: usize>(&'a [T; N]);
+        struct Nested<T>(Vec<T>);
+
+        impl_for_each! {
+            impl<'a, T: Clone + Into<Vec<u8>>, const N: usize> Len
+            for Chunk<'a, T, N>, Bytes<'a, T, N>,
+            where
+                T: Default + PartialEq<T>,
+                [T; N]: Clone,
+            {
+                fn len(&self) -> usize {
+                    self.0.iter().cloned().map(Into::<Vec<u8>>::into).map(|v| v.len()).sum()
+                }
+            }
+        }
+
+        impl_for_each! {
+            impl<T: Into<Vec<u8>>> Len for Nested<T> {
+                fn len(&self) -> usize {
+                    self.0.len()
+                }
+            }
+        }
+
+        assert_eq!(Chunk(&["ab".to_string(), "c".to_string()]).len(), 3);
+        assert_eq!(Bytes(&[Vec::from([1u8])]).len(), 1);
+        assert_eq!(Nested(Vec::from(["a", "b"])).len(), 2);
+    }
 }
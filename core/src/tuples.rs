@@ -1,6 +1,6 @@
 //! This module is held separate to put generated variadic generics for tuples
-//! at the end of the documentation so as to not disturb the reader when reading
-//! documentation.
+//! and arrays at the end of the documentation so as to not disturb the reader
+//! when reading documentation.
 //!
 //! ```
 //! # use frunk_core::hlist::*;
@@ -13,6 +13,11 @@
 //! let t2 = (999, false, "world");
 //! let h2: HList![ isize, bool, &str ] = t2.into();
 //! assert_eq!(h2, hlist![ 999, false, "world" ]);
+//!
+//! // Arrays of up to 32 elements are Generic, with a homogeneous HList as
+//! // their representation
+//! let h3: HList![ u8, u8, u8 ] = frunk_core::generic::into_generic([1u8, 2, 3]);
+//! assert_eq!(h3, hlist![ 1u8, 2, 3 ]);
 //! # }
 //! ```
 
@@ -158,3 +163,39 @@ tup_iso!(
     T23, T22, T21, T20, T19, T18, T17, T16, T15, T14, T13, T12, T11, T10, T9, T8, T7, T6, T5, T4,
     T3, T2, T1, T0
 );
+
+macro_rules! array_iso {
+    (@one $elem: ident) => { 1 };
+    (@ty $elem: ident) => { T };
+
+    () => {
+        impl<T> Generic for [T; 0] {
+            type Repr = HList![];
+            fn into(self) -> Self::Repr { hlist![] }
+            fn from(_: Self::Repr) -> Self { [] }
+        }
+    };
+
+    ( $first: ident $(, $elem: ident )* ) => {
+        array_iso!($( $elem ),*);
+
+        impl<T> Generic for [T; 1 $( + array_iso!(@one $elem) )*] {
+            type Repr = HList![T, $( array_iso!(@ty $elem), )*];
+
+            fn into(self) -> Self::Repr {
+                let [$first, $( $elem ),*] = self;
+                hlist![$first, $( $elem ),*]
+            }
+
+            fn from(r: Self::Repr) -> Self {
+                let hlist_pat![$first, $( $elem ),*] = r;
+                [$first, $( $elem ),*]
+            }
+        }
+    };
+}
+
+array_iso!(
+    e31, e30, e29, e28, e27, e26, e25, e24, e23, e22, e21, e20, e19, e18, e17, e16, e15, e14, e13,
+    e12, e11, e10, e9, e8, e7, e6, e5, e4, e3, e2, e1, e0
+);
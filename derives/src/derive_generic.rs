@@ -8,8 +8,8 @@ use syn::Data;
 /// representation for structs and a Coproduct of payload HLists for enums.
 ///
 /// Works with structs, tuple structs, and enums.
-pub fn impl_generic(input: TokenStream) -> impl ToTokens {
-    let ast = to_ast(input);
+pub fn impl_generic(input: TokenStream) -> syn::Result<impl ToTokens> {
+    let ast = to_ast(input)?;
     let name = &ast.ident;
 
     let generics = &ast.generics;
@@ -18,10 +18,13 @@ pub fn impl_generic(input: TokenStream) -> impl ToTokens {
     #[allow(clippy::let_and_return)]
     let tree = match ast.data {
         Data::Struct(ref data) => {
-            let field_bindings = FieldBindings::new(&data.fields);
+            let field_bindings = FieldBindings::new(&data.fields)?;
+            field_bindings.reject_flatten("by Generic")?;
+            field_bindings.reject_rename("by Generic")?;
             let repr_type = field_bindings.build_hlist_type(FieldBinding::build_type);
             let hcons_constr = field_bindings.build_hlist_constr(FieldBinding::build);
-            let type_constr = field_bindings.build_type_constr(FieldBinding::build);
+            let type_pat = field_bindings.build_type_constr(FieldBinding::build_pat);
+            let type_constr = field_bindings.build_type_constr(FieldBinding::build_expr);
 
             quote! {
                 #[allow(non_snake_case, non_camel_case_types)]
@@ -30,7 +33,7 @@ pub fn impl_generic(input: TokenStream) -> impl ToTokens {
                     type Repr = #repr_type;
 
                     fn into(self) -> Self::Repr {
-                        let #name #type_constr = self;
+                        let #name #type_pat = self;
                         #hcons_constr
                     }
 
@@ -42,14 +45,18 @@ pub fn impl_generic(input: TokenStream) -> impl ToTokens {
             }
         }
         Data::Enum(ref data) => {
-            let variant_bindings = VariantBindings::new(&data.variants);
+            let variant_bindings = VariantBindings::new(&data.variants)?;
+            for variant in &variant_bindings.variants {
+                variant.fields.reject_flatten("by Generic")?;
+                variant.reject_rename("by Generic")?;
+            }
             let repr_type = &variant_bindings.build_coprod_type(VariantBinding::build_hlist_type);
             let coprod_constrs =
                 &variant_bindings.build_coprod_constrs(VariantBinding::build_hlist_constr);
             let coprod_unreachable = &variant_bindings.build_coprod_unreachable_arm(false);
-            let type_constrs1 =
+            let type_pats = &variant_bindings.build_variant_constrs(VariantBinding::build_type_pat);
+            let type_constrs =
                 &variant_bindings.build_variant_constrs(VariantBinding::build_type_constr);
-            let type_constrs2 = type_constrs1;
             let name_it1 = repeat(name);
             let name_it2 = repeat(name);
 
@@ -62,7 +69,7 @@ pub fn impl_generic(input: TokenStream) -> impl ToTokens {
                     fn into(self) -> Self::Repr {
                         match self {
                             #(
-                                #name_it1 :: #type_constrs1 => #coprod_constrs,
+                                #name_it1 :: #type_pats => #coprod_constrs,
                             )*
                         }
                     }
@@ -70,7 +77,7 @@ pub fn impl_generic(input: TokenStream) -> impl ToTokens {
                     fn from(r: Self::Repr) -> Self {
                         match r {
                             #(
-                                #coprod_constrs => #name_it2 :: #type_constrs2,
+                                #coprod_constrs => #name_it2 :: #type_constrs,
                             )*
                             #coprod_unreachable
                         }
@@ -78,9 +85,14 @@ pub fn impl_generic(input: TokenStream) -> impl ToTokens {
                 }
             }
         }
-        _ => panic!("Only Structs and Enums can be turned into Generics."),
+        Data::Union(ref data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "only structs and enums can derive Generic",
+            ))
+        }
     };
 
     //     print!("{}", tree);
-    tree
+    Ok(tree)
 }
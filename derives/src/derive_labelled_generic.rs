@@ -9,9 +9,10 @@ use syn::Data;
 /// Field (see frunk_core::labelled) elements
 ///
 /// Only works with Structs and Tuple Structs
-pub fn impl_labelled_generic(input: TokenStream) -> impl ToTokens {
-    let ast = to_ast(input);
+pub fn impl_labelled_generic(input: TokenStream) -> syn::Result<impl ToTokens> {
+    let ast = to_ast(input)?;
     let name = &ast.ident;
+    let label_encoding = TypeAttrs::parse(&ast.attrs)?.label_encoding();
 
     let generics = &ast.generics;
     let generics_ref = ref_generics(generics);
@@ -21,13 +22,50 @@ pub fn impl_labelled_generic(input: TokenStream) -> impl ToTokens {
     #[allow(clippy::let_and_return)]
     let tree = match ast.data {
         Data::Struct(ref data) => {
-            let field_bindings = FieldBindings::new(&data.fields);
+            let field_bindings = FieldBindings::with_label_encoding(&data.fields, label_encoding)?;
+
+            // Flattened fields whose types depend on type parameters need bounds to
+            // compute the representation; concrete ones are simply normalized. The
+            // reference impls bound the field types themselves through
+            // `LabelledGenericRef`, since bounds on `&T` make rustc overflow whenever it
+            // looks for `&_: IntoLabelledGeneric` with an unknown type.
+            let mut generics = generics.clone();
+            let mut generics_ref = generics_ref.clone();
+            if generics.type_params().next().is_some() {
+                let predicates = field_bindings.build_flatten_predicates(
+                    FieldBinding::build_type,
+                    FieldBinding::build_field_type,
+                    quote! { ::frunk_core::labelled::LabelledGeneric },
+                );
+                add_predicates(&mut generics, predicates);
+                let ref_trait = quote! { ::frunk_core::labelled::LabelledGenericRef<'_frunk_ref_> };
+                let mut predicates_ref = field_bindings.build_flatten_predicates(
+                    FieldBinding::build_type,
+                    FieldBinding::build_field_type_ref,
+                    ref_trait.clone(),
+                );
+                predicates_ref.extend(field_bindings.build_flatten_predicates(
+                    FieldBinding::build_type,
+                    FieldBinding::build_field_type_mut,
+                    ref_trait,
+                ));
+                add_predicates(&mut generics_ref, predicates_ref);
+            }
+            let (impl_generics, _, where_clause) = generics.split_for_impl();
+            let (impl_generics_ref, _, where_clause_ref) = generics_ref.split_for_impl();
+
             let repr_type = field_bindings.build_hlist_type(FieldBinding::build_field_type);
             let repr_type_ref = field_bindings.build_hlist_type(FieldBinding::build_field_type_ref);
             let repr_type_mut = field_bindings.build_hlist_type(FieldBinding::build_field_type_mut);
             let hcons_expr = field_bindings.build_hlist_constr(FieldBinding::build_field_expr);
-            let hcons_pat = field_bindings.build_hlist_constr(FieldBinding::build_field_pat);
-            let type_constr = field_bindings.build_type_constr(FieldBinding::build);
+            let hcons_expr_ref =
+                field_bindings.build_hlist_constr(FieldBinding::build_field_expr_ref);
+            let hcons_expr_mut =
+                field_bindings.build_hlist_constr(FieldBinding::build_field_expr_mut);
+            let repr = call_site_ident("repr");
+            let hcons_destr = field_bindings.build_labelled_destr(&repr);
+            let type_pat = field_bindings.build_type_constr(FieldBinding::build_pat);
+            let type_constr = field_bindings.build_type_constr(FieldBinding::build_expr);
             let type_pat_ref = field_bindings.build_type_constr(FieldBinding::build_pat_ref);
             let type_pat_mut = field_bindings.build_type_constr(FieldBinding::build_pat_mut);
 
@@ -39,13 +77,13 @@ pub fn impl_labelled_generic(input: TokenStream) -> impl ToTokens {
 
                     #[inline(always)]
                     fn into(self) -> Self::Repr {
-                        let #name #type_constr = self;
+                        let #name #type_pat = self;
                         #hcons_expr
                     }
 
                     #[inline(always)]
-                    fn from(r: Self::Repr) -> Self {
-                        let #hcons_pat = r;
+                    fn from(#repr: Self::Repr) -> Self {
+                        #hcons_destr
                         #name #type_constr
                     }
                 }
@@ -58,7 +96,7 @@ pub fn impl_labelled_generic(input: TokenStream) -> impl ToTokens {
                     #[inline(always)]
                     fn into(self) -> Self::Repr {
                         let #name #type_pat_ref = *self;
-                        #hcons_expr
+                        #hcons_expr_ref
                     }
 
                 }
@@ -71,14 +109,18 @@ pub fn impl_labelled_generic(input: TokenStream) -> impl ToTokens {
                     #[inline(always)]
                     fn into(self) -> Self::Repr {
                         let #name #type_pat_mut = *self;
-                        #hcons_expr
+                        #hcons_expr_mut
                     }
 
                 }
             }
         }
         Data::Enum(ref data) => {
-            let variant_bindings = VariantBindings::new(&data.variants);
+            let variant_bindings =
+                VariantBindings::with_label_encoding(&data.variants, label_encoding)?;
+            for variant in &variant_bindings.variants {
+                variant.fields.reject_flatten("on enum variants")?;
+            }
             let repr_type =
                 &variant_bindings.build_coprod_type(VariantBinding::build_hlist_field_type);
             let repr_type_ref =
@@ -90,9 +132,9 @@ pub fn impl_labelled_generic(input: TokenStream) -> impl ToTokens {
             let coprod_pats =
                 &variant_bindings.build_coprod_constrs(VariantBinding::build_hlist_field_pat);
             let coprod_unreachable = &variant_bindings.build_coprod_unreachable_arm(false);
-            let type_constrs1 =
+            let type_pats = &variant_bindings.build_variant_constrs(VariantBinding::build_type_pat);
+            let type_constrs =
                 &variant_bindings.build_variant_constrs(VariantBinding::build_type_constr);
-            let type_constrs2 = type_constrs1;
             let type_pat_ref =
                 &variant_bindings.build_variant_constrs(VariantBinding::build_type_pat_ref);
             let type_pat_mut =
@@ -112,7 +154,7 @@ pub fn impl_labelled_generic(input: TokenStream) -> impl ToTokens {
                     fn into(self) -> Self::Repr {
                         match self {
                             #(
-                                #name_it1 :: #type_constrs1 => #coprod_exprs,
+                                #name_it1 :: #type_pats => #coprod_exprs,
                             )*
                         }
                     }
@@ -121,7 +163,7 @@ pub fn impl_labelled_generic(input: TokenStream) -> impl ToTokens {
                     fn from(r: Self::Repr) -> Self {
                         match r {
                             #(
-                                #coprod_pats => #name_it2 :: #type_constrs2,
+                                #coprod_pats => #name_it2 :: #type_constrs,
                             )*
                             #coprod_unreachable
                         }
@@ -167,8 +209,13 @@ pub fn impl_labelled_generic(input: TokenStream) -> impl ToTokens {
 
             quote! { #base_impl #ref_impl #mut_impl }
         }
-        _ => panic!("Only Structs and Enums can be turned into Labelled Generics."),
+        Data::Union(ref data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "only structs and enums can derive LabelledGeneric",
+            ))
+        }
     };
 
-    tree
+    Ok(tree)
 }
@@ -0,0 +1,51 @@
+use frunk_proc_macro_helpers::*;
+use proc_macro::TokenStream;
+use quote::ToTokens;
+use syn::Data;
+
+/// Given an AST, returns an implementation of Monoid whose empty value is built from
+/// the empty value of its Generic representation.
+///
+/// Only works with structs and tuple structs that are also Generic.
+pub fn impl_monoid(input: TokenStream) -> syn::Result<impl ToTokens> {
+    let ast = to_ast(input)?;
+    let name = &ast.ident;
+
+    let field_bindings = match ast.data {
+        Data::Struct(ref data) => FieldBindings::new(&data.fields)?,
+        Data::Enum(ref data) => {
+            return Err(syn::Error::new_spanned(
+                data.enum_token,
+                "only structs can derive Monoid",
+            ))
+        }
+        Data::Union(ref data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "only structs can derive Monoid",
+            ))
+        }
+    };
+    field_bindings.reject_flatten("by Monoid")?;
+    field_bindings.reject_skip("by Monoid")?;
+    field_bindings.reject_rename("by Monoid")?;
+
+    let mut generics = ast.generics.clone();
+    add_predicates(
+        &mut generics,
+        [
+            quote! { Self: ::frunk::Generic + ::frunk::semigroup::Semigroup },
+            quote! { <Self as ::frunk::Generic>::Repr: ::frunk::monoid::Monoid },
+        ],
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::frunk::monoid::Monoid for #name #ty_generics #where_clause {
+            #[inline]
+            fn empty() -> Self {
+                ::frunk::from_generic(<<Self as ::frunk::Generic>::Repr as ::frunk::monoid::Monoid>::empty())
+            }
+        }
+    })
+}
@@ -0,0 +1,54 @@
+use frunk_proc_macro_helpers::*;
+use proc_macro::TokenStream;
+use quote::ToTokens;
+use syn::Data;
+
+/// Given an AST, returns an implementation of Semigroup that combines the Generic
+/// representations of the two values.
+///
+/// Only works with structs and tuple structs that are also Generic and Clone.
+pub fn impl_semigroup(input: TokenStream) -> syn::Result<impl ToTokens> {
+    let ast = to_ast(input)?;
+    let name = &ast.ident;
+
+    let field_bindings = match ast.data {
+        Data::Struct(ref data) => FieldBindings::new(&data.fields)?,
+        Data::Enum(ref data) => {
+            return Err(syn::Error::new_spanned(
+                data.enum_token,
+                "only structs can derive Semigroup",
+            ))
+        }
+        Data::Union(ref data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "only structs can derive Semigroup",
+            ))
+        }
+    };
+    field_bindings.reject_flatten("by Semigroup")?;
+    field_bindings.reject_skip("by Semigroup")?;
+    field_bindings.reject_rename("by Semigroup")?;
+
+    let mut generics = ast.generics.clone();
+    add_predicates(
+        &mut generics,
+        [
+            quote! { Self: ::core::clone::Clone + ::frunk::Generic },
+            quote! { <Self as ::frunk::Generic>::Repr: ::frunk::semigroup::Semigroup },
+        ],
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::frunk::semigroup::Semigroup for #name #ty_generics #where_clause {
+            #[inline]
+            fn combine(&self, other: &Self) -> Self {
+                ::frunk::from_generic(::frunk::semigroup::Semigroup::combine(
+                    &::frunk::into_generic(::core::clone::Clone::clone(self)),
+                    &::frunk::into_generic(::core::clone::Clone::clone(other)),
+                ))
+            }
+        }
+    })
+}
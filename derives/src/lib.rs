@@ -23,17 +23,55 @@ use crate::derive_generic::impl_generic;
 mod derive_labelled_generic;
 use crate::derive_labelled_generic::impl_labelled_generic;
 
+mod derive_semigroup;
+use crate::derive_semigroup::impl_semigroup;
+
+mod derive_monoid;
+use crate::derive_monoid::impl_monoid;
+
 use quote::ToTokens;
 
 /// Derives a Generic instance based on HList for structs and
 /// Coproducts of payload HLists for enums.
-#[proc_macro_derive(Generic)]
+///
+/// Fields can be left out of the representation with `#[frunk(skip)]`. They are
+/// rebuilt with `Default::default()` when converting back, or by calling the
+/// function given with `#[frunk(default = "path")]`, which implies `skip`. Since
+/// skipped fields do not survive a round trip, the derived instance is then only
+/// an isomorphism up to those fields.
+///
+/// ```
+/// # use frunk::{hlist, Generic};
+/// # fn main() {
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Reading {
+///     value: f32,
+///     #[frunk(skip)]
+///     cached_label: Option<String>,
+///     #[frunk(default = "fresh_hits")]
+///     hits: u32,
+/// }
+///
+/// fn fresh_hits() -> u32 {
+///     1
+/// }
+///
+/// let reading = Reading { value: 1.5, cached_label: Some("1.5".into()), hits: 9 };
+/// let repr = Generic::into(reading);
+/// assert_eq!(repr, hlist![1.5f32]);
+///
+/// let back: Reading = Generic::from(repr);
+/// assert_eq!(back, Reading { value: 1.5, cached_label: None, hits: 1 });
+/// # }
+/// ```
+#[proc_macro_derive(Generic, attributes(frunk))]
 pub fn generic(input: TokenStream) -> TokenStream {
     // Build the impl
-    let gen = impl_generic(input);
-    //    println!("{}", gen);
-    // Return the generated impl
-    gen.into_token_stream().into()
+    match impl_generic(input) {
+        // Return the generated impl
+        Ok(gen) => gen.into_token_stream().into(),
+        Err(e) => e.into_compile_error().into(),
+    }
 }
 
 /// Derives a Generic instance based on Field + HList for
@@ -43,11 +81,190 @@ pub fn generic(input: TokenStream) -> TokenStream {
 /// There *may* be problems if your field names contain certain characters.
 /// This can be solved by adding letters to the create_enums_for! macro invocation
 /// in frunk_core::labelled via a PR :)
-#[proc_macro_derive(LabelledGeneric)]
+///
+/// Fields can be left out of the representation with `#[frunk(skip)]` or
+/// `#[frunk(default = "path")]`, just like with [`Generic`](derive@Generic).
+/// This lets types that carry caches or metrics still be converted into types
+/// that do not have them.
+///
+/// ```
+/// # use frunk::LabelledGeneric;
+/// # fn main() {
+/// #[derive(LabelledGeneric)]
+/// struct CachedUser {
+///     name: String,
+///     #[frunk(skip)]
+///     lookups: usize,
+/// }
+///
+/// #[derive(LabelledGeneric)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// let user: User = frunk::labelled_convert_from(CachedUser { name: "Joe".into(), lookups: 3 });
+/// let cached: CachedUser = frunk::labelled_convert_from(user);
+/// assert_eq!(cached.name, "Joe");
+/// assert_eq!(cached.lookups, 0);
+/// # }
+/// ```
+///
+/// The label of a field or enum variant can be changed with
+/// `#[frunk(rename = "label")]`, so that types which name the same logical field
+/// differently can still be converted into each other.
+///
+/// ```
+/// # use frunk::LabelledGeneric;
+/// # fn main() {
+/// #[derive(LabelledGeneric)]
+/// struct ApiUser {
+///     #[frunk(rename = "user_id")]
+///     id: u64,
+///     name: String,
+/// }
+///
+/// #[derive(LabelledGeneric)]
+/// struct DbUser {
+///     user_id: u64,
+///     name: String,
+/// }
+///
+/// let db_user: DbUser = frunk::labelled_convert_from(ApiUser { id: 1, name: "Joe".into() });
+/// assert_eq!(db_user.user_id, 1);
+/// # }
+/// ```
+///
+/// A struct field whose type is itself `LabelledGeneric` can be marked with
+/// `#[frunk(flatten)]` to splice its fields into the representation in its place,
+/// like `serde(flatten)`. This is not supported on enum variants.
+///
+/// ```
+/// # use frunk::LabelledGeneric;
+/// # fn main() {
+/// #[derive(LabelledGeneric)]
+/// struct Meta {
+///     id: u64,
+///     created_at: i64,
+/// }
+///
+/// #[derive(LabelledGeneric)]
+/// struct CreateUser {
+///     #[frunk(flatten)]
+///     meta: Meta,
+///     name: String,
+/// }
+///
+/// #[derive(LabelledGeneric)]
+/// struct UserRow {
+///     id: u64,
+///     name: String,
+///     created_at: i64,
+/// }
+///
+/// let meta = Meta { id: 1, created_at: 1000 };
+/// let row: UserRow = frunk::transform_from(CreateUser { meta, name: "Joe".into() });
+/// assert_eq!(row.id, 1);
+/// assert_eq!(row.created_at, 1000);
+/// # }
+/// ```
+///
+/// With `#[frunk(packed_labels)]` on the type, its labels are encoded as
+/// `frunk::labelled::Packed` instead of tuples of chars, which keeps type names short
+/// and wide structs fast to compile. Types only convert into each other when their
+/// labels use the same encoding, and their paths are written with
+/// `packed_path!`/`PackedPath!` instead of `path!`/`Path!`.
+///
+/// ```
+/// # use frunk::LabelledGeneric;
+/// # fn main() {
+/// #[derive(LabelledGeneric)]
+/// #[frunk(packed_labels)]
+/// struct Reading {
+///     sensor_identifier: u32,
+///     value: f32,
+/// }
+///
+/// #[derive(LabelledGeneric)]
+/// #[frunk(packed_labels)]
+/// struct Sample {
+///     value: f32,
+///     sensor_identifier: u32,
+/// }
+///
+/// let sample: Sample = frunk::transform_from(Reading { sensor_identifier: 7, value: 0.5 });
+/// assert_eq!(sample.sensor_identifier, 7);
+/// assert_eq!(Sample::field_names(), ["value", "sensor_identifier"]);
+/// # }
+/// ```
+#[proc_macro_derive(LabelledGeneric, attributes(frunk))]
 pub fn labelled_generic(input: TokenStream) -> TokenStream {
     // Build the impl
-    let gen = impl_labelled_generic(input);
-    //    println!("{}", gen);
-    // Return the generated impl
-    gen.into_token_stream().into()
+    match impl_labelled_generic(input) {
+        // Return the generated impl
+        Ok(gen) => gen.into_token_stream().into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+/// Derives a Semigroup instance for a struct whose fields are all Semigroups, by
+/// combining the Generic representations of the two values, which combines each
+/// field with the same field of the other value.
+///
+/// The struct must also be `Generic` and `Clone`, since the representations are
+/// built from clones of the values. `#[frunk(skip)]`, `#[frunk(default = "path")]`
+/// and `#[frunk(rename = "label")]` are rejected, because every field has to be
+/// combined for the Semigroup to be lawful.
+///
+/// ```
+/// # use frunk::{Generic, Monoid, Semigroup};
+/// # use frunk::semigroup::Max;
+/// # fn main() {
+/// #[derive(Generic, Semigroup, Monoid, Clone, Debug, PartialEq)]
+/// struct Stats {
+///     requests: u64,
+///     errors: u64,
+///     slowest: Option<Max<u32>>,
+/// }
+///
+/// let a = Stats { requests: 3, errors: 1, slowest: Some(Max(20)) };
+/// let b = Stats { requests: 2, errors: 0, slowest: Some(Max(35)) };
+/// let total = frunk::monoid::combine_all(&[a, b]);
+/// assert_eq!(total, Stats { requests: 5, errors: 1, slowest: Some(Max(35)) });
+/// # }
+/// ```
+#[proc_macro_derive(Semigroup, attributes(frunk))]
+pub fn semigroup(input: TokenStream) -> TokenStream {
+    // Build the impl
+    match impl_semigroup(input) {
+        // Return the generated impl
+        Ok(gen) => gen.into_token_stream().into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+/// Derives a Monoid instance for a struct whose fields are all Monoids, whose
+/// empty value is built from the empty value of its Generic representation, so
+/// every field is empty. The struct must also be `Generic` and a Semigroup, e.g. by
+/// deriving them.
+///
+/// As for the Semigroup derive, `#[frunk(skip)]`, `#[frunk(default = "path")]` and
+/// `#[frunk(rename = "label")]` are rejected.
+///
+/// ```
+/// # use frunk::{Generic, Monoid, Semigroup};
+/// # fn main() {
+/// #[derive(Generic, Semigroup, Monoid, Clone, Debug, PartialEq)]
+/// struct Totals(u32, String);
+///
+/// assert_eq!(<Totals as Monoid>::empty(), Totals(0, String::new()));
+/// # }
+/// ```
+#[proc_macro_derive(Monoid, attributes(frunk))]
+pub fn monoid(input: TokenStream) -> TokenStream {
+    // Build the impl
+    match impl_monoid(input) {
+        // Return the generated impl
+        Ok(gen) => gen.into_token_stream().into(),
+        Err(e) => e.into_compile_error().into(),
+    }
 }
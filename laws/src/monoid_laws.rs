@@ -110,6 +110,13 @@ mod tests {
         quickcheck(right_identity as fn(Wrapper<All<i32>>) -> bool);
     }
 
+    #[test]
+    fn hlist_id_prop() {
+        type Stats = Wrapper<frunk::HList!(String, Option<Vec<u8>>, u32)>;
+        quickcheck(left_identity as fn(Stats) -> bool);
+        quickcheck(right_identity as fn(Stats) -> bool);
+    }
+
     macro_rules! numeric_id_props {
       ($($id: ident; $tr:ty,)*) => {
 
@@ -2,6 +2,7 @@
 //! instances of typeclasses that we don't define for types we don't
 //! own
 
+use frunk::hlist::{HCons, HNil};
 use frunk::monoid::*;
 use frunk::semigroup::*;
 use quickcheck::*;
@@ -43,6 +44,25 @@ impl<A: Arbitrary> Arbitrary for Wrapper<Product<A>> {
     }
 }
 
+impl Arbitrary for Wrapper<HNil> {
+    fn arbitrary(_: &mut Gen) -> Self {
+        Wrapper(HNil)
+    }
+}
+
+impl<H: Arbitrary, T: Clone + 'static> Arbitrary for Wrapper<HCons<H, T>>
+where
+    Wrapper<T>: Arbitrary,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        let Wrapper(tail) = Wrapper::<T>::arbitrary(g);
+        Wrapper(HCons {
+            head: Arbitrary::arbitrary(g),
+            tail,
+        })
+    }
+}
+
 impl<A: Semigroup> Semigroup for Wrapper<A> {
     fn combine(&self, other: &Self) -> Self {
         Wrapper(self.0.combine(&other.0))
@@ -16,12 +16,13 @@ extern crate quote;
 extern crate syn;
 
 use proc_macro::TokenStream;
-use proc_macro2::{Span, TokenStream as TokenStream2};
+use proc_macro2::{Literal, Span, TokenStream as TokenStream2};
 use quote::ToTokens;
+use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
 use syn::{
-    DeriveInput, Expr, Field, Fields, GenericParam, Generics, Ident, Lifetime, LifetimeParam,
-    Member, Variant,
+    braced, Attribute, DeriveInput, Expr, Field, Fields, GenericParam, Generics, Ident, Index,
+    Lifetime, LifetimeParam, LitStr, Member, Pat, Path, Token, Type, Variant,
 };
 
 /// These are assumed to exist as enums in frunk_core::labelled
@@ -37,9 +38,9 @@ const UNDERSCORE_CHARS: &[char] = &['_', '0', '1', '2', '3', '4', '5', '6', '7',
 /// Parses a TokenStream (usually received as input into a
 /// custom derive function), into a syn MacroInput AST,
 /// which is nice.
-pub fn to_ast(input: TokenStream) -> DeriveInput {
+pub fn to_ast(input: TokenStream) -> syn::Result<DeriveInput> {
     // Parse the string representation
-    syn::parse(input).unwrap()
+    syn::parse(input)
 }
 
 /// Returns an Ident
@@ -114,14 +115,21 @@ pub fn build_coprod_unreachable_arm(length: usize, deref: bool) -> TokenStream2
     }
 }
 
-pub fn build_field_type(name: &Ident, inner_type: impl ToTokens) -> TokenStream2 {
-    let label_type = build_label_type(name);
+pub fn build_field_type(
+    label: &str,
+    encoding: LabelEncoding,
+    inner_type: impl ToTokens,
+) -> TokenStream2 {
+    let label_type = encoding.build_label_type(label);
     quote! { ::frunk_core::labelled::Field<#label_type, #inner_type> }
 }
-pub fn build_field_expr(name: &Ident, inner_expr: impl ToTokens) -> TokenStream2 {
-    let label_type = build_label_type(name);
-    let literal_name = name.to_string();
-    quote! { ::frunk_core::labelled::field_with_name::<#label_type, _>(#literal_name, #inner_expr) }
+pub fn build_field_expr(
+    label: &str,
+    encoding: LabelEncoding,
+    inner_expr: impl ToTokens,
+) -> TokenStream2 {
+    let label_type = encoding.build_label_type(label);
+    quote! { ::frunk_core::labelled::field_with_name::<#label_type, _>(#label, #inner_expr) }
 }
 pub fn build_field_pat(inner_pat: impl ToTokens) -> TokenStream2 {
     quote! { ::frunk_core::labelled::Field { value: #inner_pat, .. } }
@@ -132,8 +140,33 @@ pub fn build_field_pat(inner_pat: impl ToTokens) -> TokenStream2 {
 ///
 /// For example, given first_name, returns an AST for (f,i,r,s,t,__,n,a,m,e)
 pub fn build_label_type(ident: &Ident) -> impl ToTokens {
-    let as_string = ident.to_string();
-    let name = as_string.as_str();
+    build_chars_label_type(&ident.to_string())
+}
+
+/// The ways a label can be represented at the type level.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum LabelEncoding {
+    /// A tuple of the enums in frunk_core::labelled::chars (see [`build_chars_label_type`]).
+    #[default]
+    Chars,
+    /// A frunk_core::labelled::Packed (see [`build_packed_label_type`]).
+    Packed,
+}
+
+impl LabelEncoding {
+    /// Given a label, returns an AST for its type level representation in this encoding.
+    /// The label does not have to be a valid identifier.
+    pub fn build_label_type(self, name: &str) -> TokenStream2 {
+        match self {
+            LabelEncoding::Chars => build_chars_label_type(name),
+            LabelEncoding::Packed => build_packed_label_type(name),
+        }
+    }
+}
+
+/// Given a label, returns an AST for its type level representation as a tuple of
+/// the enums in frunk_core::labelled::chars.
+pub fn build_chars_label_type(name: &str) -> TokenStream2 {
     let name_as_idents: Vec<Ident> = name.chars().flat_map(|c| encode_as_ident(&c)).collect();
     let name_as_tokens: Vec<_> = name_as_idents
         .iter()
@@ -144,6 +177,31 @@ pub fn build_label_type(ident: &Ident) -> impl ToTokens {
     quote! { (#(#name_as_tokens),*) }
 }
 
+/// Given a label, returns an AST for its type level representation as a
+/// frunk_core::labelled::Packed.
+///
+/// For example, given first_name, returns an AST for
+/// `Packed<0x66697273745f6e616d65000000000000>`, where the number is the bytes of
+/// the label padded with zeros.
+pub fn build_packed_label_type(name: &str) -> TokenStream2 {
+    let mut chunks: Vec<[u8; 16]> = name
+        .as_bytes()
+        .chunks(16)
+        .map(|chunk| {
+            let mut bytes = [0; 16];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            bytes
+        })
+        .collect();
+    if chunks.is_empty() {
+        chunks.push([0; 16]);
+    }
+    chunks.iter().rev().fold(quote! { () }, |rest, chunk| {
+        let bytes = Literal::u128_unsuffixed(u128::from_be_bytes(*chunk));
+        quote! { ::frunk_core::labelled::Packed<#bytes, #rest> }
+    })
+}
+
 /// Given a char, encodes it as a vector of Ident
 ///
 /// Takes care of checking to see whether the char can be used as is,
@@ -172,11 +230,11 @@ fn encode_as_ident(c: &char) -> Vec<Ident> {
     }
 }
 
-pub fn build_path_type(path_expr: Expr) -> impl ToTokens {
-    let idents = find_idents_in_expr(path_expr);
-    idents
+pub fn build_path_type(path_expr: Expr, encoding: LabelEncoding) -> syn::Result<impl ToTokens> {
+    let idents = find_idents_in_expr(path_expr)?;
+    Ok(idents
         .iter()
-        .map(build_label_type)
+        .map(|ident| encoding.build_label_type(&ident.to_string()))
         .fold(quote!(::frunk_core::hlist::HNil), |acc, t| {
             quote! {
             ::frunk_core::path::Path<
@@ -186,12 +244,12 @@ pub fn build_path_type(path_expr: Expr) -> impl ToTokens {
                 >
               >
             }
-        })
+        }))
 }
 
 /// Returns the idents in a path like expression in reverse
-pub fn find_idents_in_expr(path_expr: Expr) -> Vec<Ident> {
-    fn go(current: Expr, mut v: Vec<Ident>) -> Vec<Ident> {
+pub fn find_idents_in_expr(path_expr: Expr) -> syn::Result<Vec<Ident>> {
+    fn go(current: Expr, mut v: Vec<Ident>) -> syn::Result<Vec<Ident>> {
         match current {
             Expr::Field(e) => {
                 let m = e.member;
@@ -199,34 +257,294 @@ pub fn find_idents_in_expr(path_expr: Expr) -> Vec<Ident> {
                     Member::Named(i) => {
                         v.push(i);
                     }
-                    _ => panic!("Only named access is supported"),
+                    Member::Unnamed(index) => {
+                        return Err(syn::Error::new_spanned(
+                            index,
+                            "only named field access is supported in a path",
+                        ))
+                    }
                 }
                 go(*e.base, v)
             }
             Expr::Path(p) => {
                 if p.path.segments.len() != 1 {
-                    panic!("Invalid name; this has collons in it")
+                    Err(syn::Error::new_spanned(
+                        p,
+                        "expected a field name, found a path with `::` in it",
+                    ))
                 } else {
                     let i = p.path.segments[0].ident.clone();
                     v.push(i);
-                    v
+                    Ok(v)
                 }
             }
-            _ => panic!("Invalid input"),
+            other => Err(syn::Error::new_spanned(
+                other,
+                "expected a path of field names, like `a.b.c`",
+            )),
         }
     }
     go(path_expr, Vec::new())
 }
 
+/// The parsed input of the `coprod_match!` macro:
+/// `value, { pat: Type => body, Type => body, ... }`
+pub struct CoprodMatch {
+    pub value: Expr,
+    pub arms: Vec<CoprodMatchArm>,
+}
+
+/// A single arm of `coprod_match!`; the pattern is optional and defaults to `_`.
+pub struct CoprodMatchArm {
+    pub pat: Option<Pat>,
+    pub ty: Type,
+    pub body: Expr,
+}
+
+impl Parse for CoprodMatch {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let value = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let content;
+        braced!(content in input);
+        let mut arms = Vec::new();
+        while !content.is_empty() {
+            let arm: CoprodMatchArm = content.parse()?;
+            let needs_comma = !content.is_empty() && !expr_is_block_like(&arm.body);
+            arms.push(arm);
+            if needs_comma {
+                content.parse::<Token![,]>()?;
+            } else {
+                content.parse::<Option<Token![,]>>()?;
+            }
+        }
+        input.parse::<Option<Token![,]>>()?;
+        Ok(CoprodMatch { value, arms })
+    }
+}
+
+impl Parse for CoprodMatchArm {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pat = if has_pat(input) {
+            let pat = Pat::parse_single(input)?;
+            input.parse::<Token![:]>()?;
+            Some(pat)
+        } else {
+            None
+        };
+        let ty = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let body = input.parse()?;
+        Ok(CoprodMatchArm { pat, ty, body })
+    }
+}
+
+/// Whether an arm starts with `pat: Type =>`, rather than just `Type =>`.
+///
+/// This parses the whole `pat: Type =>` on a fork instead of peeking past the pattern,
+/// because a type with a leading `::`, as in `s: ::std::string::String`, puts a `:` right
+/// before a `::`, which peeking for `::` cannot tell apart from a bare `::std::...` type.
+fn has_pat(input: ParseStream) -> bool {
+    let fork = input.fork();
+    Pat::parse_single(&fork).is_ok()
+        && fork.parse::<Token![:]>().is_ok()
+        && fork.parse::<Type>().is_ok()
+        && fork.peek(Token![=>])
+}
+
+/// Whether an expression can end a match arm without a trailing comma.
+fn expr_is_block_like(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Block(_)
+            | Expr::If(_)
+            | Expr::Match(_)
+            | Expr::Loop(_)
+            | Expr::While(_)
+            | Expr::ForLoop(_)
+            | Expr::Unsafe(_)
+            | Expr::TryBlock(_)
+            | Expr::Const(_)
+    )
+}
+
+/// Builds an exhaustive, type-directed match over a Coproduct.
+///
+/// Each arm uninjects its type from the remainder of the previous arm, and the
+/// final remainder is required to be `CNil`, so that missing arms are a compile error.
+pub fn build_coprod_match(coprod_match: CoprodMatch) -> TokenStream2 {
+    let remainder = Ident::new("__frunk_coprod_remainder", Span::mixed_site());
+    let value = &coprod_match.value;
+    let exhausted = quote_spanned! { value.span() =>
+        {
+            let #remainder: ::frunk_core::coproduct::CNil = #remainder;
+            match #remainder {}
+        }
+    };
+    let matched = coprod_match
+        .arms
+        .iter()
+        .rev()
+        .fold(exhausted, |otherwise, arm| {
+            let ty = &arm.ty;
+            let body = &arm.body;
+            let pat = match arm.pat {
+                Some(ref pat) => quote! { #pat },
+                None => quote! { _ },
+            };
+            let uninjected = quote_spanned! { ty.span() =>
+                ::frunk_core::coproduct::CoprodUninjector::<#ty, _>::uninject(#remainder)
+            };
+            quote! {
+                match #uninjected {
+                    ::core::result::Result::Ok(#pat) => #body,
+                    ::core::result::Result::Err(#remainder) => #otherwise,
+                }
+            }
+        });
+    quote! {
+        {
+            let #remainder = #value;
+            #matched
+        }
+    }
+}
+
 pub enum StructType {
     Named,
     Tuple,
     Unit,
 }
 
+/// The options set on a field with `#[frunk(...)]` attributes.
+#[derive(Default)]
+pub struct FieldAttrs {
+    /// `#[frunk(skip)]`: leave the field out of the representation.
+    pub skip: bool,
+    /// `#[frunk(default = "path")]`: rebuild a skipped field by calling `path()`
+    /// instead of `Default::default()`. Implies `skip`.
+    pub default: Option<Path>,
+    /// `#[frunk(rename = "label")]`: use `label` instead of the field name as its
+    /// label in a `LabelledGeneric` representation.
+    pub rename: Option<String>,
+    /// `#[frunk(flatten)]`: splice the fields of the field's own `LabelledGeneric`
+    /// representation into the representation.
+    pub flatten: bool,
+}
+
+impl FieldAttrs {
+    /// Parses the `#[frunk(...)]` attributes in `attrs`, ignoring any others.
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut parsed = FieldAttrs::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("frunk")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    parsed.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    let path: LitStr = meta.value()?.parse()?;
+                    parsed.skip = true;
+                    parsed.default = Some(path.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    parsed.rename = Some(parse_rename(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("flatten") {
+                    parsed.flatten = true;
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "unsupported frunk attribute, expected `skip`, `default`, `rename` or `flatten`",
+                    ))
+                }
+            })?;
+            if parsed.flatten && (parsed.skip || parsed.rename.is_some()) {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "`flatten` cannot be combined with `skip`, `default` or `rename`",
+                ));
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+/// The options set on a struct or enum with `#[frunk(...)]` attributes.
+#[derive(Default)]
+pub struct TypeAttrs {
+    /// `#[frunk(packed_labels)]`: encode the labels of the `LabelledGeneric`
+    /// representation as frunk_core::labelled::Packed instead of tuples of chars.
+    pub packed_labels: bool,
+}
+
+impl TypeAttrs {
+    /// Parses the `#[frunk(...)]` attributes in `attrs`, ignoring any others.
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut parsed = TypeAttrs::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("frunk")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("packed_labels") {
+                    parsed.packed_labels = true;
+                    Ok(())
+                } else {
+                    Err(meta
+                        .error("unsupported frunk attribute on a type, expected `packed_labels`"))
+                }
+            })?;
+        }
+        Ok(parsed)
+    }
+
+    /// The encoding of the labels of the `LabelledGeneric` representation.
+    pub fn label_encoding(&self) -> LabelEncoding {
+        if self.packed_labels {
+            LabelEncoding::Packed
+        } else {
+            LabelEncoding::Chars
+        }
+    }
+}
+
+/// The options set on an enum variant with `#[frunk(...)]` attributes.
+#[derive(Default)]
+pub struct VariantAttrs {
+    /// `#[frunk(rename = "label")]`: use `label` instead of the variant name as its
+    /// label in a `LabelledGeneric` representation.
+    pub rename: Option<String>,
+}
+
+impl VariantAttrs {
+    /// Parses the `#[frunk(...)]` attributes in `attrs`, ignoring any others.
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut parsed = VariantAttrs::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("frunk")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    parsed.rename = Some(parse_rename(&meta)?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported frunk attribute on a variant, expected `rename`"))
+                }
+            })?;
+        }
+        Ok(parsed)
+    }
+}
+
+fn parse_rename(meta: &syn::meta::ParseNestedMeta) -> syn::Result<String> {
+    let label: LitStr = meta.value()?.parse()?;
+    if label.value().is_empty() {
+        return Err(syn::Error::new(label.span(), "a label cannot be empty"));
+    }
+    Ok(label.value())
+}
+
 pub struct FieldBinding {
     pub field: Field,
+    pub index: usize,
     pub binding: Ident,
+    pub attrs: FieldAttrs,
+    pub label_encoding: LabelEncoding,
 }
 
 impl FieldBinding {
@@ -246,25 +564,118 @@ impl FieldBinding {
         let binding = &self.binding;
         quote! { #binding }
     }
+    /// Builds the pattern for this field in a struct or variant pattern, ignoring
+    /// skipped fields.
+    pub fn build_pat(&self) -> TokenStream2 {
+        self.build_pat_with(quote! {})
+    }
     pub fn build_pat_ref(&self) -> TokenStream2 {
-        let binding = &self.binding;
-        quote! { ref #binding }
+        self.build_pat_with(quote! { ref })
     }
     pub fn build_pat_mut(&self) -> TokenStream2 {
+        self.build_pat_with(quote! { ref mut })
+    }
+    fn build_pat_with(&self, mode: TokenStream2) -> TokenStream2 {
         let binding = &self.binding;
-        quote! { ref mut #binding }
+        match (self.attrs.skip, &self.field.ident) {
+            (false, _) => quote! { #mode #binding },
+            (true, Some(name)) => quote! { #name: _ },
+            (true, None) => quote! { _ },
+        }
+    }
+    /// Builds the expression for this field in a struct or variant constructor,
+    /// rebuilding skipped fields from their default.
+    pub fn build_expr(&self) -> TokenStream2 {
+        if !self.attrs.skip {
+            return self.build();
+        }
+        self.build_expr_with(self.build_default())
     }
+    /// Builds the expression for this field in a struct or variant constructor
+    /// with the given value.
+    pub fn build_expr_with(&self, value: impl ToTokens) -> TokenStream2 {
+        match self.field.ident {
+            Some(ref name) => quote! { #name: #value },
+            None => quote! { #value },
+        }
+    }
+    /// Builds the expression rebuilding this field when it is skipped.
+    pub fn build_default(&self) -> TokenStream2 {
+        match self.attrs.default {
+            Some(ref path) => quote_spanned! { path.span() => #path() },
+            None => quote_spanned! { self.field.ty.span() => ::core::default::Default::default() },
+        }
+    }
+    /// The member used to access this field, i.e. its name or its index.
+    pub fn member(&self) -> Member {
+        match self.field.ident {
+            Some(ref name) => Member::Named(name.clone()),
+            None => Member::Unnamed(Index {
+                index: self.index as u32,
+                span: self.field.span(),
+            }),
+        }
+    }
+    /// The label of this field in a `LabelledGeneric` representation.
+    pub fn label(&self) -> String {
+        match self.attrs.rename {
+            Some(ref label) => label.clone(),
+            None => self.binding.to_string(),
+        }
+    }
+    /// Builds the type this field contributes to a `LabelledGeneric` representation:
+    /// a `Field`, or the whole representation of a flattened field.
     pub fn build_field_type(&self) -> TokenStream2 {
-        build_field_type(&self.binding, self.build_type())
+        self.build_field_type_with(self.build_type())
     }
+    /// Like [`FieldBinding::build_field_type`], for the representation of a reference
+    /// to the struct. Flattened fields go through `LabelledGenericRef`, so that the
+    /// bounds they need in generic structs are on the field type itself.
     pub fn build_field_type_ref(&self) -> TokenStream2 {
-        build_field_type(&self.binding, self.build_type_ref())
+        if self.attrs.flatten {
+            self.build_flattened_ref_type(quote! { Ref })
+        } else {
+            build_field_type(&self.label(), self.label_encoding, self.build_type_ref())
+        }
     }
     pub fn build_field_type_mut(&self) -> TokenStream2 {
-        build_field_type(&self.binding, self.build_type_mut())
+        if self.attrs.flatten {
+            self.build_flattened_ref_type(quote! { Mut })
+        } else {
+            build_field_type(&self.label(), self.label_encoding, self.build_type_mut())
+        }
+    }
+    fn build_field_type_with(&self, ty: TokenStream2) -> TokenStream2 {
+        if self.attrs.flatten {
+            quote! { <#ty as ::frunk_core::labelled::IntoLabelledGeneric>::Repr }
+        } else {
+            build_field_type(&self.label(), self.label_encoding, ty)
+        }
+    }
+    fn build_flattened_ref_type(&self, repr: TokenStream2) -> TokenStream2 {
+        let ty = self.build_type();
+        quote! { <#ty as ::frunk_core::labelled::LabelledGenericRef<'_frunk_ref_>>::#repr }
     }
     pub fn build_field_expr(&self) -> TokenStream2 {
-        build_field_expr(&self.binding, &self.binding)
+        self.build_field_expr_with(quote! { ::frunk_core::labelled::IntoLabelledGeneric::into })
+    }
+    pub fn build_field_expr_ref(&self) -> TokenStream2 {
+        self.build_field_expr_with(
+            quote! { ::frunk_core::labelled::LabelledGenericRef::to_repr_ref },
+        )
+    }
+    pub fn build_field_expr_mut(&self) -> TokenStream2 {
+        self.build_field_expr_with(
+            quote! { ::frunk_core::labelled::LabelledGenericRef::to_repr_mut },
+        )
+    }
+    fn build_field_expr_with(&self, flatten: TokenStream2) -> TokenStream2 {
+        let binding = &self.binding;
+        if self.attrs.flatten {
+            quote! { #flatten(#binding) }
+        } else {
+            build_field_expr(&self.label(), self.label_encoding, binding)
+        }
     }
     pub fn build_field_pat(&self) -> TokenStream2 {
         build_field_pat(&self.binding)
@@ -279,8 +690,16 @@ pub struct FieldBindings {
 }
 
 impl FieldBindings {
-    pub fn new(fields: &Fields) -> Self {
-        Self {
+    pub fn new(fields: &Fields) -> syn::Result<Self> {
+        Self::with_label_encoding(fields, LabelEncoding::default())
+    }
+
+    /// Like [`FieldBindings::new`], with the labels of the fields in the given encoding.
+    pub fn with_label_encoding(
+        fields: &Fields,
+        label_encoding: LabelEncoding,
+    ) -> syn::Result<Self> {
+        Ok(Self {
             type_: match fields {
                 Fields::Named(_) => StructType::Named,
                 Fields::Unnamed(_) => StructType::Tuple,
@@ -289,15 +708,20 @@ impl FieldBindings {
             fields: fields
                 .iter()
                 .enumerate()
-                .map(|(index, field)| FieldBinding {
-                    field: field.clone(),
-                    binding: field
-                        .ident
-                        .clone()
-                        .unwrap_or_else(|| Ident::new(&format!("_{}", index), field.span())),
+                .map(|(index, field)| {
+                    Ok(FieldBinding {
+                        field: field.clone(),
+                        index,
+                        binding: field
+                            .ident
+                            .clone()
+                            .unwrap_or_else(|| Ident::new(&format!("_{}", index), field.span())),
+                        attrs: FieldAttrs::parse(&field.attrs)?,
+                        label_encoding,
+                    })
                 })
-                .collect(),
-        }
+                .collect::<syn::Result<_>>()?,
+        })
     }
 
     /// Builds a type constructor for use with structs or enum variants. Does not include the name
@@ -311,12 +735,161 @@ impl FieldBindings {
         }
     }
 
+    /// The fields that make up the representation, i.e. those that are not skipped.
+    pub fn repr_fields(&self) -> impl DoubleEndedIterator<Item = &FieldBinding> {
+        self.fields.iter().filter(|field| !field.attrs.skip)
+    }
+
+    /// Returns an error spanned on the first flattened field, for representations that
+    /// do not support `#[frunk(flatten)]`.
+    pub fn reject_flatten(&self, context: &str) -> syn::Result<()> {
+        match self.fields.iter().find(|field| field.attrs.flatten) {
+            Some(field) => Err(syn::Error::new_spanned(
+                &field.field,
+                format!("`#[frunk(flatten)]` is not supported {}", context),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns an error spanning the first renamed field, for derives whose
+    /// representations have no labels.
+    pub fn reject_rename(&self, context: &str) -> syn::Result<()> {
+        match self
+            .fields
+            .iter()
+            .find(|field| field.attrs.rename.is_some())
+        {
+            Some(field) => Err(syn::Error::new_spanned(
+                &field.field,
+                format!("`#[frunk(rename)]` is not supported {}", context),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns an error spanning the first skipped field, for derives that need every
+    /// field to be in the representation.
+    pub fn reject_skip(&self, context: &str) -> syn::Result<()> {
+        match self.fields.iter().find(|field| field.attrs.skip) {
+            Some(field) => {
+                let attr = if field.attrs.default.is_some() {
+                    "default"
+                } else {
+                    "skip"
+                };
+                Err(syn::Error::new_spanned(
+                    &field.field,
+                    format!("`#[frunk({})]` is not supported {}", attr, context),
+                ))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Builds the HList type of the representation. The representations of flattened
+    /// fields are appended to the rest of the HList instead of being its head.
     pub fn build_hlist_type<R: ToTokens>(&self, f: impl Fn(&FieldBinding) -> R) -> TokenStream2 {
-        build_hlist_type(self.fields.iter().map(f))
+        self.build_hlist_types(f).swap_remove(0)
     }
 
+    /// Builds the HList type of every suffix of the representation, starting with the
+    /// whole representation and ending with `HNil`.
+    fn build_hlist_types<R: ToTokens>(&self, f: impl Fn(&FieldBinding) -> R) -> Vec<TokenStream2> {
+        let mut result = vec![quote! { ::frunk_core::hlist::HNil }];
+        for field in self.repr_fields().rev() {
+            let item = f(field);
+            let tail = &result[0];
+            let hlist = if field.attrs.flatten {
+                quote! { <#item as ::core::ops::Add<#tail>>::Output }
+            } else {
+                quote! { ::frunk_core::hlist::HCons<#item, #tail> }
+            };
+            result.insert(0, hlist);
+        }
+        result
+    }
+
+    /// Builds the HList constructor of the representation. The representations of
+    /// flattened fields are appended to the rest of the HList instead of being its head,
+    /// so the result can only be used as an expression when there are any.
     pub fn build_hlist_constr<R: ToTokens>(&self, f: impl Fn(&FieldBinding) -> R) -> TokenStream2 {
-        build_hlist_constr(self.fields.iter().map(f))
+        let mut result = quote! { ::frunk_core::hlist::HNil };
+        for field in self.repr_fields().rev() {
+            let item = f(field);
+            result = if field.attrs.flatten {
+                quote! { ::core::ops::Add::add(#item, #result) }
+            } else {
+                quote! { ::frunk_core::hlist::HCons { head: #item, tail: #result } }
+            };
+        }
+        result
+    }
+
+    /// Builds statements that move the fields of the `LabelledGeneric` representation
+    /// held in `repr` into their bindings, splitting flattened fields back off and
+    /// rebuilding them from their own representations.
+    pub fn build_labelled_destr(&self, repr: &Ident) -> TokenStream2 {
+        let rest_types = self.build_hlist_types(FieldBinding::build_field_type);
+        let statements = self
+            .repr_fields()
+            .zip(rest_types.iter().skip(1))
+            .map(|(field, rest)| {
+                let binding = &field.binding;
+                if field.attrs.flatten {
+                    let ty = field.build_type();
+                    let flat_repr = field.build_field_type();
+                    quote! {
+                        let (#binding, #repr) =
+                            <#flat_repr as ::frunk_core::hlist::HSplit<#rest>>::split(#repr);
+                        let #binding = <#ty as ::frunk_core::labelled::LabelledGeneric>::from(#binding);
+                    }
+                } else {
+                    let pat = field.build_field_pat();
+                    quote! {
+                        let ::frunk_core::hlist::HCons { head: #pat, tail: #repr } = #repr;
+                    }
+                }
+            });
+        quote! {
+            #(#statements)*
+            let ::frunk_core::hlist::HNil = #repr;
+        }
+    }
+
+    /// Builds the where clause predicates needed by the representations of flattened
+    /// fields, given the functions building the type of a field and the type it
+    /// contributes to the representation, and the trait providing that representation.
+    pub fn build_flatten_predicates(
+        &self,
+        ty: impl Fn(&FieldBinding) -> TokenStream2,
+        field_ty: impl Fn(&FieldBinding) -> TokenStream2,
+        trait_: TokenStream2,
+    ) -> Vec<TokenStream2> {
+        let rest_types = self.build_hlist_types(&field_ty);
+        self.repr_fields()
+            .zip(rest_types.iter().skip(1))
+            .filter(|(field, _)| field.attrs.flatten)
+            .flat_map(|(field, rest)| {
+                let ty = ty(field);
+                let flat_repr = field_ty(field);
+                vec![
+                    quote! { #ty: #trait_ },
+                    quote! { #flat_repr: ::frunk_core::hlist::HSplit<#rest> },
+                ]
+            })
+            .collect()
+    }
+}
+
+/// Adds the given predicates to the where clause of `generics`.
+pub fn add_predicates(
+    generics: &mut Generics,
+    predicates: impl IntoIterator<Item = impl ToTokens>,
+) {
+    let where_clause = generics.make_where_clause();
+    for predicate in predicates {
+        where_clause.predicates.push(syn::parse_quote!(#predicate));
     }
 }
 
@@ -346,12 +919,38 @@ pub fn ref_generics(generics: &Generics) -> Generics {
 pub struct VariantBinding {
     pub name: Ident,
     pub fields: FieldBindings,
+    pub attrs: VariantAttrs,
+    pub label_encoding: LabelEncoding,
 }
 
 impl VariantBinding {
+    /// The label of this variant in a `LabelledGeneric` representation.
+    pub fn label(&self) -> String {
+        match self.attrs.rename {
+            Some(ref label) => label.clone(),
+            None => self.name.to_string(),
+        }
+    }
+
+    /// Returns an error spanning this variant or its first field if either is renamed,
+    /// for derives whose representations have no labels.
+    pub fn reject_rename(&self, context: &str) -> syn::Result<()> {
+        if self.attrs.rename.is_some() {
+            return Err(syn::Error::new_spanned(
+                &self.name,
+                format!("`#[frunk(rename)]` is not supported {}", context),
+            ));
+        }
+        self.fields.reject_rename(context)
+    }
     pub fn build_type_constr(&self) -> TokenStream2 {
         let name = &self.name;
-        let constr = self.fields.build_type_constr(FieldBinding::build);
+        let constr = self.fields.build_type_constr(FieldBinding::build_expr);
+        quote! { #name #constr }
+    }
+    pub fn build_type_pat(&self) -> TokenStream2 {
+        let name = &self.name;
+        let constr = self.fields.build_type_constr(FieldBinding::build_pat);
         quote! { #name #constr }
     }
     pub fn build_type_pat_ref(&self) -> TokenStream2 {
@@ -366,27 +965,31 @@ impl VariantBinding {
     }
     pub fn build_hlist_field_type(&self) -> TokenStream2 {
         build_field_type(
-            &self.name,
+            &self.label(),
+            self.label_encoding,
             self.fields.build_hlist_type(FieldBinding::build_field_type),
         )
     }
     pub fn build_hlist_field_type_ref(&self) -> TokenStream2 {
         build_field_type(
-            &self.name,
+            &self.label(),
+            self.label_encoding,
             self.fields
                 .build_hlist_type(FieldBinding::build_field_type_ref),
         )
     }
     pub fn build_hlist_field_type_mut(&self) -> TokenStream2 {
         build_field_type(
-            &self.name,
+            &self.label(),
+            self.label_encoding,
             self.fields
                 .build_hlist_type(FieldBinding::build_field_type_mut),
         )
     }
     pub fn build_hlist_field_expr(&self) -> TokenStream2 {
         build_field_expr(
-            &self.name,
+            &self.label(),
+            self.label_encoding,
             self.fields
                 .build_hlist_constr(FieldBinding::build_field_expr),
         )
@@ -410,16 +1013,32 @@ pub struct VariantBindings {
 }
 
 impl VariantBindings {
-    pub fn new<'a>(data: impl IntoIterator<Item = &'a Variant>) -> Self {
-        VariantBindings {
+    pub fn new<'a>(data: impl IntoIterator<Item = &'a Variant>) -> syn::Result<Self> {
+        Self::with_label_encoding(data, LabelEncoding::default())
+    }
+
+    /// Like [`VariantBindings::new`], with the labels of the variants and their fields
+    /// in the given encoding.
+    pub fn with_label_encoding<'a>(
+        data: impl IntoIterator<Item = &'a Variant>,
+        label_encoding: LabelEncoding,
+    ) -> syn::Result<Self> {
+        Ok(VariantBindings {
             variants: data
                 .into_iter()
-                .map(|variant| VariantBinding {
-                    name: variant.ident.clone(),
-                    fields: FieldBindings::new(&variant.fields),
+                .map(|variant| {
+                    Ok(VariantBinding {
+                        name: variant.ident.clone(),
+                        fields: FieldBindings::with_label_encoding(
+                            &variant.fields,
+                            label_encoding,
+                        )?,
+                        attrs: VariantAttrs::parse(&variant.attrs)?,
+                        label_encoding,
+                    })
                 })
-                .collect(),
-        }
+                .collect::<syn::Result<_>>()?,
+        })
     }
 
     pub fn build_coprod_type<R: ToTokens>(&self, f: impl Fn(&VariantBinding) -> R) -> TokenStream2 {
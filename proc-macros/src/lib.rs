@@ -22,8 +22,41 @@ use syn::{parse_macro_input, Expr};
 /// Build a generic path that can be used for traversals
 #[proc_macro]
 pub fn path(input: TokenStream) -> TokenStream {
+    build_path_expr(input, LabelEncoding::Chars)
+}
+
+/// Build a generic path that can be used for traversals
+#[proc_macro]
+#[allow(non_snake_case)]
+pub fn Path(input: TokenStream) -> TokenStream {
+    build_path(input, LabelEncoding::Chars)
+}
+
+/// Like [`path!`], for traversing types that derive `LabelledGeneric` with
+/// `#[frunk(packed_labels)]`, whose labels are `frunk_core::labelled::Packed`.
+///
+/// Every type along the path has to pack its labels.
+#[proc_macro]
+pub fn packed_path(input: TokenStream) -> TokenStream {
+    build_path_expr(input, LabelEncoding::Packed)
+}
+
+/// Like [`Path!`], for traversing types that derive `LabelledGeneric` with
+/// `#[frunk(packed_labels)]`, whose labels are `frunk_core::labelled::Packed`.
+///
+/// Every type along the path has to pack its labels.
+#[proc_macro]
+#[allow(non_snake_case)]
+pub fn PackedPath(input: TokenStream) -> TokenStream {
+    build_path(input, LabelEncoding::Packed)
+}
+
+fn build_path_expr(input: TokenStream, encoding: LabelEncoding) -> TokenStream {
     let expr = parse_macro_input!(input as Expr);
-    let path_type = build_path_type(expr);
+    let path_type = match build_path_type(expr, encoding) {
+        Ok(path_type) => path_type,
+        Err(e) => return e.into_compile_error().into(),
+    };
     let ast = quote! {
         {
             let p: #path_type = ::frunk_core::path::Path::new();
@@ -34,15 +67,62 @@ pub fn path(input: TokenStream) -> TokenStream {
     TokenStream::from(ast)
 }
 
-/// Build a generic path that can be used for traversals
-#[proc_macro]
-#[allow(non_snake_case)]
-pub fn Path(input: TokenStream) -> TokenStream {
+fn build_path(input: TokenStream, encoding: LabelEncoding) -> TokenStream {
     let expr = parse_macro_input!(input as Expr);
-    let path_type = build_path_type(expr);
+    let path_type = match build_path_type(expr, encoding) {
+        Ok(path_type) => path_type,
+        Err(e) => return e.into_compile_error().into(),
+    };
     let ast = quote! {
         #path_type
     };
     //    println!("ast: [{}]", ast);
     TokenStream::from(ast)
 }
+
+/// Exhaustively match on the variants of a Coproduct by type
+///
+/// Each arm is written as `pattern: Type => expression`, or just
+/// `Type => expression` when the value is not needed. Arms may appear in any
+/// order, and every variant of the Coproduct must be handled exactly once; a
+/// missing arm is a compile-time error.
+///
+/// # Examples
+///
+/// ```
+/// # use frunk_core::Coprod;
+/// # use frunk_proc_macros::coprod_match;
+/// # fn main() {
+/// type Event = Coprod!(i32, String, bool);
+///
+/// fn describe(event: Event) -> String {
+///     coprod_match!(event, {
+///         b: bool => format!("flag {}", b),
+///         n: i32 => format!("number {}", n),
+///         String => "some text".to_string(),
+///     })
+/// }
+///
+/// assert_eq!(describe(Event::inject(3)), "number 3");
+/// assert_eq!(describe(Event::inject(true)), "flag true");
+/// assert_eq!(describe(Event::inject(String::from("hi"))), "some text");
+/// # }
+/// ```
+///
+/// Leaving out a variant does not compile:
+///
+/// ```compile_fail
+/// # use frunk_core::Coprod;
+/// # use frunk_proc_macros::coprod_match;
+/// # fn main() {
+/// let co = <Coprod!(i32, bool)>::inject(3);
+/// let n = coprod_match!(co, {
+///     n: i32 => n,
+/// });
+/// # }
+/// ```
+#[proc_macro]
+pub fn coprod_match(input: TokenStream) -> TokenStream {
+    let coprod_match = parse_macro_input!(input as CoprodMatch);
+    TokenStream::from(build_coprod_match(coprod_match))
+}
@@ -289,6 +289,12 @@ pub mod prelude {
     //! The intent here is that `use frunk::prelude::*` is enough to provide
     //! access to any missing methods advertised in frunk's documentation.
 
+    #[doc(no_inline)]
+    pub use crate::coproduct::CoproductResultExt;
+    #[doc(no_inline)]
+    pub use crate::coproduct::CoproductVariants; // for LEN
+    #[doc(no_inline)]
+    pub use crate::coproduct::Distribute;
     #[doc(no_inline)]
     pub use crate::hlist::HList; // for LEN
     #[doc(no_inline)]
@@ -42,6 +42,7 @@ use super::semigroup::{All, Any, Product, Semigroup};
 use alloc::{string::String, vec::Vec};
 #[cfg(feature = "std")]
 use core::hash::Hash;
+use frunk_core::hlist::{HCons, HList, HNil};
 #[cfg(feature = "std")]
 use std::collections::*;
 
@@ -117,6 +118,23 @@ where
     }
 }
 
+/// The empty HList is the combination of the empty values of its elements, so
+/// any HList of Monoids is a Monoid
+impl<H: Monoid, T: HList + Monoid> Monoid for HCons<H, T> {
+    fn empty() -> Self {
+        HCons {
+            head: H::empty(),
+            tail: T::empty(),
+        }
+    }
+}
+
+impl Monoid for HNil {
+    fn empty() -> Self {
+        HNil
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl Monoid for String {
     fn empty() -> Self {
@@ -309,6 +327,16 @@ mod tests {
         assert_eq!(combine_n(&Some(2), 4), Some(8));
     }
 
+    #[test]
+    fn test_hlist_empty() {
+        use frunk_core::hlist;
+        assert_eq!(
+            <frunk_core::HList![i32, Option<u8>, All<bool>] as Monoid>::empty(),
+            hlist![0, None, All(true)]
+        );
+        assert_eq!(combine_n(&hlist![2, Some(1)], 3), hlist![6, Some(3)]);
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn test_combine_all_basic() {
@@ -1,4 +1,5 @@
 use frunk_core::{hlist, Coprod};
+use frunk_proc_macros::coprod_match;
 
 #[test]
 fn test_inject_coproduct() {
@@ -40,3 +41,54 @@ fn test_coproduct_fold_non_consuming() {
         "t".to_string()
     );
 }
+
+#[test]
+fn test_coprod_match() {
+    type I32StrBool = Coprod!(i32, String, bool);
+
+    fn describe(co: I32StrBool) -> String {
+        coprod_match!(co, {
+            s: String => {
+                if s.is_empty() {
+                    return "empty".to_string();
+                }
+                format!("string {}", s)
+            }
+            b: bool => format!("bool {}", b),
+            i32 => "int".to_string(),
+        })
+    }
+
+    assert_eq!(describe(I32StrBool::inject(3)), "int");
+    assert_eq!(describe(I32StrBool::inject(false)), "bool false");
+    assert_eq!(describe(I32StrBool::inject(String::new())), "empty");
+    assert_eq!(describe(I32StrBool::inject("hi".to_string())), "string hi");
+}
+
+#[test]
+fn test_coprod_match_by_ref() {
+    type I32Bool = Coprod!(i32, bool);
+
+    let co = I32Bool::inject(3);
+    let doubled = coprod_match!(co.to_ref(), {
+        &b: &bool => if b { 1 } else { 0 },
+        &n: &i32 => n * 2,
+    });
+    assert_eq!(doubled, 6);
+    assert_eq!(co, I32Bool::inject(3));
+}
+
+#[test]
+fn test_coprod_match_absolute_paths() {
+    type StrOrU8 = Coprod!(::std::string::String, u8);
+
+    fn describe(co: StrOrU8) -> String {
+        coprod_match!(co, {
+            s: ::std::string::String => s,
+            ::core::primitive::u8 => "byte".to_string(),
+        })
+    }
+
+    assert_eq!(describe(StrOrU8::inject("hi".to_string())), "hi");
+    assert_eq!(describe(StrOrU8::inject(3u8)), "byte");
+}
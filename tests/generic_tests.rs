@@ -113,6 +113,134 @@ fn test_bool_generic() {
     assert!(from_generic::<bool, _>(expected_true));
 }
 
+#[test]
+fn test_array_generic() {
+    let repr = into_generic([1, 2, 3]);
+    assert_eq!(repr, hlist![1, 2, 3]);
+    assert_eq!(from_generic::<[i32; 3], _>(repr), [1, 2, 3]);
+
+    assert_eq!(into_generic::<[i32; 0], _>([]), hlist![]);
+    let big: [u8; 32] = core::array::from_fn(|i| i as u8);
+    assert_eq!(from_generic::<[u8; 32], _>(into_generic(big)), big);
+}
+
+#[test]
+fn test_ordering_generic() {
+    use std::cmp::Ordering;
+
+    let less = into_generic(Ordering::Less);
+    let greater = into_generic(Ordering::Greater);
+
+    assert_eq!(less, Coproduct::Inl(hlist![]));
+    assert_eq!(
+        greater,
+        Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(hlist![])))
+    );
+    assert_eq!(from_generic::<Ordering, _>(less), Ordering::Less);
+    assert_eq!(
+        from_generic::<Ordering, _>(into_generic(Ordering::Equal)),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn test_bound_generic() {
+    use std::ops::Bound;
+
+    let included = into_generic(Bound::Included(1));
+    let unbounded = into_generic(Bound::<i32>::Unbounded);
+
+    assert_eq!(included, Coproduct::Inl(hlist![1]));
+    assert_eq!(
+        unbounded,
+        Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(hlist![])))
+    );
+    assert_eq!(from_generic::<Bound<i32>, _>(included), Bound::Included(1));
+    assert_eq!(
+        from_generic::<Bound<i32>, _>(into_generic(Bound::Excluded(2))),
+        Bound::Excluded(2)
+    );
+}
+
+#[test]
+fn test_range_generic() {
+    use std::ops::{Range, RangeInclusive};
+
+    assert_eq!(into_generic(1..4), hlist![1, 4]);
+    assert_eq!(into_generic(1..=4), hlist![1, 4]);
+    assert_eq!(from_generic::<Range<i32>, _>(hlist![1, 4]), 1..4);
+    assert_eq!(from_generic::<RangeInclusive<i32>, _>(hlist![1, 4]), 1..=4);
+
+    let inclusive: RangeInclusive<i32> = Generic::convert_from(1..4);
+    assert_eq!(inclusive, 1..=4);
+}
+
+#[test]
+fn test_cow_generic() {
+    use std::borrow::Cow;
+
+    let borrowed = into_generic(Cow::Borrowed("hi"));
+    let owned = into_generic(Cow::<str>::Owned(String::from("hi")));
+
+    assert_eq!(borrowed, Coproduct::Inl(hlist!["hi"]));
+    assert_eq!(
+        owned,
+        Coproduct::Inr(Coproduct::Inl(hlist![String::from("hi")]))
+    );
+    assert!(matches!(
+        from_generic::<Cow<str>, _>(borrowed),
+        Cow::Borrowed("hi")
+    ));
+    assert!(matches!(from_generic::<Cow<str>, _>(owned), Cow::Owned(_)));
+}
+
+#[test]
+fn test_skipped_fields_generic() {
+    fn unknown() -> &'static str {
+        "unknown"
+    }
+
+    #[derive(Generic, Debug, PartialEq)]
+    struct Tagged(
+        i32,
+        #[frunk(skip)] Vec<u8>,
+        #[frunk(default = "unknown")] &'static str,
+    );
+
+    #[derive(Generic, Debug, PartialEq)]
+    enum Event {
+        Click {
+            x: i32,
+            #[frunk(skip)]
+            handled: bool,
+        },
+        Key(#[frunk(skip)] u64, char),
+    }
+
+    let repr = into_generic(Tagged(1, vec![2], "tag"));
+    assert_eq!(repr, hlist![1]);
+    assert_eq!(
+        from_generic::<Tagged, _>(repr),
+        Tagged(1, vec![], "unknown")
+    );
+
+    let click = into_generic(Event::Click {
+        x: 3,
+        handled: true,
+    });
+    let key = into_generic(Event::Key(99, 'k'));
+    assert_eq!(click, Coproduct::Inl(hlist![3]));
+    assert_eq!(key, Coproduct::Inr(Coproduct::Inl(hlist!['k'])));
+    assert_eq!(
+        from_generic::<Event, _>(click),
+        Event::Click {
+            x: 3,
+            handled: false
+        }
+    );
+    assert_eq!(from_generic::<Event, _>(key), Event::Key(0, 'k'));
+}
+
 #[test]
 fn test_struct_conversion() {
     let a = Strategist {
@@ -162,3 +290,96 @@ fn test_mixed_conversions_round_trip() {
     let u_again: SavedUser = convert_from(au);
     assert_eq!(u_again, before)
 }
+
+mod counting {
+    use frunk::coproduct::{CNil, Coproduct};
+    use frunk::hlist::{HCons, HNil};
+    use frunk::{into_generic, Generic};
+
+    /// Counts the values of type `T` in a value.
+    pub trait CountOf<T> {
+        fn count_of(&self) -> usize;
+    }
+
+    impl CountOf<u8> for u8 {
+        fn count_of(&self) -> usize {
+            1
+        }
+    }
+
+    impl CountOf<u8> for &str {
+        fn count_of(&self) -> usize {
+            0
+        }
+    }
+
+    impl CountOf<u8> for HNil {
+        fn count_of(&self) -> usize {
+            0
+        }
+    }
+
+    impl<H: CountOf<u8>, T: CountOf<u8>> CountOf<u8> for HCons<H, T> {
+        fn count_of(&self) -> usize {
+            self.head.count_of() + self.tail.count_of()
+        }
+    }
+
+    impl CountOf<u8> for CNil {
+        fn count_of(&self) -> usize {
+            match *self {}
+        }
+    }
+
+    impl<H: CountOf<u8>, T: CountOf<u8>> CountOf<u8> for Coproduct<H, T> {
+        fn count_of(&self) -> usize {
+            match self {
+                Coproduct::Inl(h) => h.count_of(),
+                Coproduct::Inr(t) => t.count_of(),
+            }
+        }
+    }
+
+    #[derive(Generic, Clone)]
+    pub struct Rgb(pub u8, pub u8, pub u8);
+
+    #[derive(Generic, Clone)]
+    pub enum Color {
+        Named(&'static str),
+        Grey(u8),
+    }
+
+    #[derive(Generic, Clone)]
+    pub struct Pair<T>(pub T, pub T);
+
+    frunk::impl_for_each! {
+        impl self::CountOf<u8> for Rgb, Color, {
+            fn count_of(&self) -> usize {
+                into_generic(self.clone()).count_of()
+            }
+        }
+    }
+
+    frunk::impl_for_each! {
+        impl<T: Clone + CountOf<u8>> CountOf<u8> for Pair<T>
+        where
+            Self: Clone + Generic,
+            <Self as Generic>::Repr: CountOf<u8>,
+        {
+            fn count_of(&self) -> usize {
+                into_generic(self.clone()).count_of()
+            }
+        }
+    }
+}
+
+#[test]
+fn test_impl_for_each() {
+    use counting::{Color, CountOf, Pair, Rgb};
+
+    assert_eq!(Rgb(1, 2, 3).count_of(), 3);
+    assert_eq!(Color::Named("red").count_of(), 0);
+    assert_eq!(Color::Grey(7).count_of(), 1);
+    assert_eq!(Pair(1u8, 2).count_of(), 2);
+    assert_eq!(Pair(Color::Grey(1), Color::Named("red")).count_of(), 1);
+}
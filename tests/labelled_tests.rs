@@ -1,6 +1,7 @@
 use frunk::hlist::Sculptor;
 use frunk::labelled::chars::*;
 use frunk::labelled::Field;
+use frunk::labelled::IntoLabelledGeneric;
 use frunk::labelled::Transmogrifier;
 use frunk::{from_labelled_generic, into_labelled_generic, transform_from};
 use frunk::{Coproduct, HCons, LabelledGeneric};
@@ -298,6 +299,319 @@ fn test_bool_labelled_generic() {
     assert!(from_labelled_generic::<bool, _>(expected_true));
 }
 
+#[test]
+fn test_ordering_labelled_generic() {
+    use std::cmp::Ordering;
+
+    let equal = into_labelled_generic(Ordering::Equal);
+
+    assert_eq!(
+        equal,
+        Coproduct::Inr(Coproduct::Inl(field!((E, q, u, a, l), hlist![])))
+    );
+    assert_eq!(from_labelled_generic::<Ordering, _>(equal), Ordering::Equal);
+}
+
+#[test]
+fn test_bound_labelled_generic() {
+    use std::ops::Bound;
+
+    let excluded = into_labelled_generic(Bound::Excluded(3));
+    let unbounded = into_labelled_generic(Bound::<i32>::Unbounded);
+
+    assert_eq!(
+        excluded,
+        Coproduct::Inr(Coproduct::Inl(field!(
+            (E, x, c, l, u, d, e, d),
+            hlist![field!((__, _0), 3, "_0")]
+        )))
+    );
+    assert_eq!(
+        unbounded,
+        Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(field!(
+            (U, n, b, o, u, n, d, e, d),
+            hlist![]
+        ))))
+    );
+    assert_eq!(
+        from_labelled_generic::<Bound<i32>, _>(excluded),
+        Bound::Excluded(3)
+    );
+}
+
+#[test]
+fn test_range_labelled_generic() {
+    use std::ops::{Range, RangeInclusive};
+
+    #[derive(LabelledGeneric, PartialEq, Debug)]
+    struct Span {
+        end: u8,
+        start: u8,
+    }
+
+    let repr = into_labelled_generic(2u8..5);
+    assert_eq!(
+        repr,
+        hlist![field!((s, t, a, r, t), 2u8), field!((e, n, d), 5u8)]
+    );
+    assert_eq!(from_labelled_generic::<Range<u8>, _>(repr), 2..5);
+
+    let span: Span = transform_from(2u8..=5);
+    assert_eq!(span, Span { start: 2, end: 5 });
+    let range: RangeInclusive<u8> = transform_from(span);
+    assert_eq!(range, 2..=5);
+}
+
+#[test]
+fn test_cow_labelled_generic() {
+    use std::borrow::Cow;
+
+    let owned = into_labelled_generic(Cow::<str>::Owned(String::from("hi")));
+    assert_eq!(
+        owned,
+        Coproduct::Inr(Coproduct::Inl(field!(
+            (O, w, n, e, d),
+            hlist![field!((__, _0), String::from("hi"), "_0")]
+        )))
+    );
+    assert_eq!(from_labelled_generic::<Cow<str>, _>(owned), "hi");
+}
+
+#[test]
+fn test_skipped_fields_labelled_generic() {
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    struct CachedUser<'a> {
+        name: &'a str,
+        #[frunk(skip)]
+        lookups: usize,
+        age: u8,
+    }
+
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    struct User<'a> {
+        age: u8,
+        name: &'a str,
+    }
+
+    let mut cached = CachedUser {
+        name: "Joe",
+        lookups: 7,
+        age: 30,
+    };
+    assert_eq!(
+        IntoLabelledGeneric::into(&cached),
+        hlist![field!((n, a, m, e), &"Joe"), field!((a, g, e), &30)]
+    );
+    *IntoLabelledGeneric::into(&mut cached).tail.head.value += 1;
+
+    let user: User = transform_from(cached);
+    assert_eq!(
+        user,
+        User {
+            age: 31,
+            name: "Joe"
+        }
+    );
+    let cached: CachedUser = transform_from(user);
+    assert_eq!(
+        cached,
+        CachedUser {
+            name: "Joe",
+            lookups: 0,
+            age: 31
+        }
+    );
+}
+
+#[test]
+fn test_renamed_fields_labelled_generic() {
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    struct ApiUser<'a> {
+        #[frunk(rename = "user_id")]
+        id: u64,
+        #[frunk(rename = "full name")]
+        name: &'a str,
+    }
+
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    struct DbUser<'a> {
+        user_id: u64,
+        #[frunk(rename = "full name")]
+        display_name: &'a str,
+    }
+
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    struct Profile<'a> {
+        user: DbUser<'a>,
+        followers: u32,
+    }
+
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    struct ApiProfile<'a> {
+        #[frunk(rename = "user")]
+        account: ApiUser<'a>,
+        followers: u32,
+    }
+
+    let api_user = ApiUser { id: 1, name: "Joe" };
+    let repr = into_labelled_generic(api_user);
+    assert_eq!(repr.head.name, "user_id");
+    assert_eq!(repr.tail.head.name, "full name");
+    assert_eq!(ApiUser::field_names(), ["user_id", "full name"]);
+
+    let db_user: DbUser = transform_from(from_labelled_generic::<ApiUser, _>(repr));
+    assert_eq!(
+        db_user,
+        DbUser {
+            user_id: 1,
+            display_name: "Joe"
+        }
+    );
+
+    let api_profile: ApiProfile = Profile {
+        user: db_user,
+        followers: 3,
+    }
+    .transmogrify();
+    assert_eq!(
+        api_profile,
+        ApiProfile {
+            account: ApiUser { id: 1, name: "Joe" },
+            followers: 3
+        }
+    );
+}
+
+#[test]
+fn test_renamed_variants_labelled_generic() {
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    enum Old {
+        #[frunk(rename = "Active")]
+        Enabled {
+            since: u32,
+        },
+        Disabled,
+    }
+
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    enum New {
+        Active { since: u32 },
+        Disabled,
+    }
+
+    let new: New = frunk::labelled_convert_from(Old::Enabled { since: 2 });
+    assert_eq!(new, New::Active { since: 2 });
+    let old: Old = frunk::labelled_convert_from(New::Disabled);
+    assert_eq!(old, Old::Disabled);
+}
+
+#[test]
+fn test_flattened_fields_labelled_generic() {
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    struct Meta {
+        id: u64,
+        created_at: i64,
+    }
+
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    struct CreateUser<'a> {
+        #[frunk(flatten)]
+        meta: Meta,
+        name: &'a str,
+    }
+
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    struct UserRow<'a> {
+        id: u64,
+        name: &'a str,
+        created_at: i64,
+    }
+
+    let create = CreateUser {
+        meta: Meta {
+            id: 1,
+            created_at: 1000,
+        },
+        name: "Joe",
+    };
+    assert_eq!(
+        IntoLabelledGeneric::into(&create),
+        hlist![
+            field!((i, d), &1),
+            field!((c, r, e, a, t, e, d, __, a, t), &1000, "created_at"),
+            field!((n, a, m, e), &"Joe")
+        ]
+    );
+
+    let row: UserRow = transform_from(create);
+    assert_eq!(
+        row,
+        UserRow {
+            id: 1,
+            name: "Joe",
+            created_at: 1000
+        }
+    );
+    let create: CreateUser = transform_from(row);
+    assert_eq!(
+        create,
+        CreateUser {
+            meta: Meta {
+                id: 1,
+                created_at: 1000
+            },
+            name: "Joe"
+        }
+    );
+}
+
+#[test]
+fn test_flattened_generic_fields_labelled_generic() {
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    struct Versioned<T> {
+        version: u32,
+        #[frunk(flatten)]
+        data: T,
+    }
+
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    struct VersionedPoint {
+        x: i32,
+        y: i32,
+        version: u32,
+    }
+
+    let mut versioned = Versioned {
+        version: 2,
+        data: Point { x: 1, y: 3 },
+    };
+    *IntoLabelledGeneric::into(&mut versioned).tail.head.value += 1;
+
+    let flat: VersionedPoint = transform_from(versioned);
+    assert_eq!(
+        flat,
+        VersionedPoint {
+            x: 2,
+            y: 3,
+            version: 2
+        }
+    );
+    let versioned: Versioned<Point> = transform_from(flat);
+    assert_eq!(
+        versioned,
+        Versioned {
+            version: 2,
+            data: Point { x: 2, y: 3 }
+        }
+    );
+}
+
 #[test]
 fn test_sculpt_enum() {
     let value = LabelledEnum1::VariantC {
@@ -335,3 +649,169 @@ fn test_transmogrify_keyword_field_embedder_structs() {
     let result: HasKeyword2Embedder = value.transmogrify();
     assert_eq!(3, result.r#true.r#type);
 }
+
+mod audit {
+    use frunk::labelled::diff::{diff_fields_into, diff_value_into, Diff, FieldChange};
+    use frunk::LabelledGeneric;
+
+    #[derive(Debug, PartialEq)]
+    pub enum Status {
+        Active,
+        Suspended { reason: String },
+    }
+
+    #[derive(LabelledGeneric)]
+    pub struct Limits {
+        pub daily: u32,
+        pub monthly: Option<u32>,
+    }
+
+    #[derive(LabelledGeneric)]
+    pub struct Account<'a> {
+        #[frunk(rename = "account_id")]
+        pub id: u64,
+        pub owner: &'a str,
+        pub status: Status,
+        pub limits: Limits,
+    }
+
+    impl Diff for Limits {
+        fn diff_into(
+            &self,
+            other: &Self,
+            path: &mut Vec<&'static str>,
+            changes: &mut Vec<FieldChange>,
+        ) {
+            diff_fields_into(self, other, path, changes)
+        }
+    }
+
+    impl Diff for Status {
+        fn diff_into(
+            &self,
+            other: &Self,
+            path: &mut Vec<&'static str>,
+            changes: &mut Vec<FieldChange>,
+        ) {
+            diff_value_into(self, other, path, changes)
+        }
+    }
+}
+
+#[test]
+fn test_labelled_diff() {
+    use audit::*;
+    use frunk::labelled::diff::diff;
+
+    let before = Account {
+        id: 1,
+        owner: "Joe",
+        status: Status::Active,
+        limits: Limits {
+            daily: 10,
+            monthly: None,
+        },
+    };
+    let after = Account {
+        id: 2,
+        owner: "Joe",
+        status: Status::Suspended {
+            reason: "fraud".to_string(),
+        },
+        limits: Limits {
+            daily: 10,
+            monthly: Some(100),
+        },
+    };
+
+    assert!(diff(&before, &before).is_empty());
+    let changes: Vec<String> = diff(&before, &after)
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        changes,
+        [
+            "account_id: 1 -> 2",
+            r#"status: Active -> Suspended { reason: "fraud" }"#,
+            "limits.monthly: None -> Some(100)",
+        ]
+    );
+}
+
+#[test]
+fn test_structural_eq() {
+    use frunk::labelled::structural_eq;
+
+    #[derive(LabelledGeneric)]
+    struct ApiUser {
+        #[frunk(rename = "user_id")]
+        id: u64,
+        name: String,
+        tags: Vec<&'static str>,
+    }
+
+    #[derive(LabelledGeneric)]
+    struct DbUser<'a> {
+        name: &'a str,
+        tags: Vec<&'static str>,
+        user_id: u64,
+    }
+
+    let api_user = ApiUser {
+        id: 7,
+        name: "Joe".to_string(),
+        tags: vec!["admin"],
+    };
+    let db_user = DbUser {
+        name: "Joe",
+        tags: vec!["admin"],
+        user_id: 7,
+    };
+    assert!(structural_eq(&api_user, &db_user));
+    assert!(structural_eq(&db_user, &api_user));
+
+    let other_user = DbUser {
+        tags: vec![],
+        ..db_user
+    };
+    assert!(!structural_eq(&api_user, &other_user));
+}
+
+#[test]
+fn test_field_names() {
+    use core::any::type_name;
+
+    #[derive(LabelledGeneric)]
+    struct Meta {
+        id: u64,
+        created_at: i64,
+    }
+
+    #[derive(LabelledGeneric)]
+    struct Row<'a> {
+        #[frunk(flatten)]
+        meta: Meta,
+        #[frunk(rename = "userName")]
+        name: &'a str,
+        #[frunk(skip)]
+        #[allow(dead_code)]
+        cached: Option<u32>,
+        tags: Vec<String>,
+    }
+
+    assert_eq!(Meta::field_names(), ["id", "created_at"]);
+    assert_eq!(Row::field_names(), ["id", "created_at", "userName", "tags"]);
+
+    let mut columns = Vec::new();
+    Row::for_each_field_type(&mut |name, ty| columns.push((name, ty)));
+    assert_eq!(
+        columns,
+        [
+            ("id", type_name::<u64>()),
+            ("created_at", type_name::<i64>()),
+            ("userName", type_name::<&str>()),
+            ("tags", type_name::<Vec<String>>()),
+        ]
+    );
+}
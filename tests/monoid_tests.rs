@@ -0,0 +1,62 @@
+use frunk::monoid::{combine_all, combine_n};
+use frunk::semigroup::{All, Max, Min};
+use frunk::{Generic, Monoid, Semigroup};
+
+#[derive(Generic, Semigroup, Monoid, Debug, PartialEq, Clone)]
+struct RequestStats {
+    count: u64,
+    failures: u64,
+    fastest: Option<Min<u32>>,
+    slowest: Option<Max<u32>>,
+    healthy: All<bool>,
+}
+
+#[derive(Generic, Semigroup, Monoid, Debug, PartialEq, Clone)]
+struct Tagged<T>(T, String);
+
+#[test]
+fn test_derived_semigroup() {
+    let a = RequestStats {
+        count: 2,
+        failures: 1,
+        fastest: Some(Min(12)),
+        slowest: Some(Max(40)),
+        healthy: All(true),
+    };
+    let b = RequestStats {
+        count: 3,
+        failures: 0,
+        fastest: Some(Min(9)),
+        slowest: None,
+        healthy: All(false),
+    };
+    assert_eq!(
+        a.combine(&b),
+        RequestStats {
+            count: 5,
+            failures: 1,
+            fastest: Some(Min(9)),
+            slowest: Some(Max(40)),
+            healthy: All(false),
+        }
+    );
+}
+
+#[test]
+fn test_derived_monoid() {
+    assert_eq!(
+        <RequestStats as Monoid>::empty(),
+        RequestStats {
+            count: 0,
+            failures: 0,
+            fastest: None,
+            slowest: None,
+            healthy: All(true),
+        }
+    );
+    assert_eq!(combine_all::<RequestStats>(&[]), RequestStats::empty());
+
+    let tagged = Tagged(Some(2), "a".to_string());
+    assert_eq!(combine_n(&tagged, 3), Tagged(Some(6), "aaa".to_string()));
+    assert_eq!(combine_n(&tagged, 0), Tagged(None, String::new()));
+}
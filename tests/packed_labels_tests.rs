@@ -0,0 +1,175 @@
+//! Tests for `LabelledGeneric` types whose labels are packed with
+//! `#[frunk(packed_labels)]`.
+
+use frunk::labelled::{Field, Label, Packed, Transmogrifier};
+use frunk::path::PathTraverser;
+use frunk::{labelled_convert_from, transform_from, LabelledGeneric};
+use frunk_proc_macros::{packed_path, path, PackedPath};
+
+#[derive(LabelledGeneric, Debug, PartialEq)]
+#[frunk(packed_labels)]
+struct NewUser<'a> {
+    first_name: &'a str,
+    last_name: &'a str,
+    #[frunk(rename = "years of age")]
+    age: usize,
+}
+
+#[derive(LabelledGeneric, Debug, PartialEq)]
+#[frunk(packed_labels)]
+struct SavedUser<'a> {
+    #[frunk(rename = "years of age")]
+    age_in_years: usize,
+    first_name: &'a str,
+}
+
+#[test]
+fn test_derived_labels_are_packed() {
+    type FirstName = Packed<0x66697273745f6e616d65000000000000>;
+    let repr = <NewUser as LabelledGeneric>::into(NewUser {
+        first_name: "Joe",
+        last_name: "Blow",
+        age: 30,
+    });
+    let first: Field<FirstName, &str> = repr.head;
+    assert_eq!(first.name, "first_name");
+    assert_eq!(FirstName::NAME, "first_name");
+    assert_eq!(
+        NewUser::field_names(),
+        ["first_name", "last_name", "years of age"]
+    );
+}
+
+#[test]
+fn test_transform_with_packed_labels() {
+    let saved: SavedUser = transform_from(NewUser {
+        first_name: "Joe",
+        last_name: "Blow",
+        age: 30,
+    });
+    assert_eq!(
+        saved,
+        SavedUser {
+            age_in_years: 30,
+            first_name: "Joe"
+        }
+    );
+}
+
+#[test]
+fn test_long_packed_labels() {
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    #[frunk(packed_labels)]
+    struct Wide {
+        a_field_name_longer_than_one_chunk: u8,
+    }
+
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    #[frunk(packed_labels)]
+    struct Outer {
+        inner: Wide,
+    }
+
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    #[frunk(packed_labels)]
+    struct OtherWide {
+        a_field_name_longer_than_one_chunk: u8,
+    }
+
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    #[frunk(packed_labels)]
+    struct OtherOuter {
+        inner: OtherWide,
+    }
+
+    assert_eq!(Wide::field_names(), ["a_field_name_longer_than_one_chunk"]);
+    let outer = Outer {
+        inner: Wide {
+            a_field_name_longer_than_one_chunk: 1,
+        },
+    };
+    assert_eq!(
+        *packed_path!(inner.a_field_name_longer_than_one_chunk).get(&outer),
+        1
+    );
+    let other: OtherOuter = outer.transmogrify();
+    assert_eq!(
+        other,
+        OtherOuter {
+            inner: OtherWide {
+                a_field_name_longer_than_one_chunk: 1
+            }
+        }
+    );
+}
+
+#[test]
+fn test_paths_with_packed_labels() {
+    fn first_name<'a, A, Idx>(user: &'a A) -> &'a str
+    where
+        &'a A: PathTraverser<PackedPath!(first_name), Idx, TargetValue = &'a &'a str>,
+    {
+        packed_path!(first_name).get(user)
+    }
+
+    let user = NewUser {
+        first_name: "Joe",
+        last_name: "Blow",
+        age: 30,
+    };
+    assert_eq!(first_name(&user), "Joe");
+}
+
+#[test]
+fn test_enums_with_packed_labels() {
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    #[frunk(packed_labels)]
+    enum Maybe<T> {
+        Nothing,
+        Just { value: T },
+    }
+
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    #[frunk(packed_labels)]
+    enum Perhaps<T> {
+        Nothing,
+        Just { value: T },
+    }
+
+    let perhaps: Perhaps<u8> = labelled_convert_from(Maybe::Just { value: 3 });
+    assert_eq!(perhaps, Perhaps::Just { value: 3 });
+    let maybe: Maybe<u8> = labelled_convert_from(Perhaps::Nothing::<u8>);
+    assert_eq!(maybe, Maybe::Nothing);
+}
+
+#[test]
+fn test_packed_and_char_labels_coexist() {
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    #[frunk(packed_labels)]
+    struct PackedPoint {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(LabelledGeneric, Debug, PartialEq)]
+    #[frunk(packed_labels)]
+    struct OtherPackedPoint {
+        y: i32,
+        x: i32,
+    }
+
+    let point = Point { x: 1, y: 2 };
+    let packed = PackedPoint { x: 1, y: 2 };
+    assert_eq!(*path!(y).get(&point), 2);
+    assert_eq!(*packed_path!(y).get(&packed), 2);
+    assert_eq!(Point::field_names(), PackedPoint::field_names());
+
+    let other: OtherPackedPoint = transform_from(packed);
+    assert_eq!(other, OtherPackedPoint { y: 2, x: 1 });
+}
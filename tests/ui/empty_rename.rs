@@ -0,0 +1,9 @@
+use frunk::LabelledGeneric;
+
+#[derive(LabelledGeneric)]
+struct User {
+    #[frunk(rename = "")]
+    name: String,
+}
+
+fn main() {}
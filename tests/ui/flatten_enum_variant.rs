@@ -0,0 +1,17 @@
+use frunk::LabelledGeneric;
+
+#[derive(LabelledGeneric)]
+struct Meta {
+    id: u64,
+}
+
+#[derive(LabelledGeneric)]
+enum Event {
+    Created {
+        #[frunk(flatten)]
+        meta: Meta,
+    },
+    Deleted,
+}
+
+fn main() {}
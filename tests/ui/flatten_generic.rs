@@ -0,0 +1,15 @@
+use frunk::Generic;
+
+#[derive(Generic)]
+struct Meta {
+    id: u64,
+}
+
+#[derive(Generic)]
+struct User {
+    #[frunk(flatten)]
+    meta: Meta,
+    name: String,
+}
+
+fn main() {}
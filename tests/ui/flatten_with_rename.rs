@@ -0,0 +1,15 @@
+use frunk::LabelledGeneric;
+
+#[derive(LabelledGeneric)]
+struct Meta {
+    id: u64,
+}
+
+#[derive(LabelledGeneric)]
+struct User {
+    #[frunk(flatten, rename = "meta")]
+    info: Meta,
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use frunk::Generic;
+
+#[derive(Generic)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use frunk::LabelledGeneric;
+
+#[derive(LabelledGeneric)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
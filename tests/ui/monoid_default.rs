@@ -0,0 +1,14 @@
+use frunk::{Generic, Monoid, Semigroup};
+
+fn next_generation() -> u32 {
+    7
+}
+
+#[derive(Generic, Semigroup, Monoid, Clone)]
+struct Cached {
+    total: i32,
+    #[frunk(default = "next_generation")]
+    generation: u32,
+}
+
+fn main() {}
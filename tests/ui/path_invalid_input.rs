@@ -0,0 +1,5 @@
+use frunk_proc_macros::Path;
+
+type Invalid = Path!(user.name());
+
+fn main() {}
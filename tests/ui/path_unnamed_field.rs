@@ -0,0 +1,5 @@
+use frunk_proc_macros::path;
+
+fn main() {
+    let _ = path!(pair.0);
+}
@@ -0,0 +1,10 @@
+use frunk::Generic;
+
+#[derive(Generic)]
+struct User {
+    #[frunk(rename = "user_id")]
+    id: u64,
+    name: String,
+}
+
+fn main() {}
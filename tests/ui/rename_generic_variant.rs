@@ -0,0 +1,10 @@
+use frunk::Generic;
+
+#[derive(Generic)]
+enum Status {
+    #[frunk(rename = "Enabled")]
+    Active,
+    Disabled,
+}
+
+fn main() {}
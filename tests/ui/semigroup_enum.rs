@@ -0,0 +1,9 @@
+use frunk::Semigroup;
+
+#[derive(Semigroup)]
+enum Total {
+    Count(u32),
+    Sum(u32),
+}
+
+fn main() {}
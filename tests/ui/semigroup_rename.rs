@@ -0,0 +1,9 @@
+use frunk::Semigroup;
+
+#[derive(Semigroup, Clone)]
+struct Totals {
+    #[frunk(rename = "sum")]
+    total: i32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use frunk::{Generic, Semigroup};
+
+#[derive(Generic, Semigroup, Clone)]
+struct Cached {
+    total: i32,
+    #[frunk(skip)]
+    lookups: usize,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use frunk::LabelledGeneric;
+
+#[derive(LabelledGeneric)]
+struct User {
+    #[frunk(ignore)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use frunk::LabelledGeneric;
+
+#[derive(LabelledGeneric)]
+#[frunk(packed)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
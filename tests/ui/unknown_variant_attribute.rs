@@ -0,0 +1,10 @@
+use frunk::Generic;
+
+#[derive(Generic)]
+enum Shape {
+    #[frunk(skip)]
+    Circle(f32),
+    Square(f32),
+}
+
+fn main() {}